@@ -0,0 +1,49 @@
+// Clone vs borrow vs move, timed. Run with `cargo bench`
+use std::hint::black_box;
+use std::rc::Rc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use ownership::costs;
+
+fn pass_string(c: &mut Criterion) {
+    let s = "x".repeat(1024);
+    let mut group = c.benchmark_group("pass 1KB String");
+    group.bench_function("by clone", |b| {
+        b.iter(|| costs::length_by_clone(black_box(&s).clone()))
+    });
+    group.bench_function("by &str", |b| {
+        b.iter(|| costs::length_by_ref(black_box(&s)))
+    });
+    group.finish();
+}
+
+fn build_vec(c: &mut Criterion) {
+    let source: Vec<String> = (0..100).map(|i| format!("string number {}", i)).collect();
+    let mut group = c.benchmark_group("build Vec<String>");
+    group.bench_function("clone from slice", |b| {
+        b.iter(|| costs::collect_by_clone(black_box(&source)))
+    });
+    group.bench_function("drain", |b| {
+        // the source has to be rebuilt for every drain, only the drain itself is timed
+        b.iter_batched(
+            || source.clone(),
+            |mut source| costs::collect_by_drain(black_box(&mut source)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn share(c: &mut Criterion) {
+    let s = "x".repeat(1024);
+    let rc = Rc::new(s.clone());
+    let mut group = c.benchmark_group("second owner");
+    group.bench_function("Rc::clone", |b| b.iter(|| costs::share_rc(black_box(&rc))));
+    group.bench_function("String::clone", |b| {
+        b.iter(|| costs::share_string(black_box(&s)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, pass_string, build_vec, share);
+criterion_main!(benches);
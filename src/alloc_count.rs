@@ -0,0 +1,154 @@
+// Counting Allocations -----------------------------------------
+// A global allocator that forwards to the system allocator and counts every call, so "clone allocates,
+// borrowing doesn't" becomes a number. Only compiled with the `count-allocs` feature, because it
+// replaces the allocator of every binary that links this crate
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::deep_clone::{self, CloneCounter, DeepBuffer};
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub deallocations: usize,
+    pub bytes: usize,
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // const-initialised Cells need no allocation and no destructor, so the allocator can touch them
+    static THREAD_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static THREAD_BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+pub struct CountingAlloc;
+
+// SAFETY: every call is forwarded unchanged to `System`, the counters don't touch the memory
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        // try_with: the thread-locals may already be gone while a thread is shutting down
+        let _ = THREAD_ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        let _ = THREAD_BYTES.try_with(|n| n.set(n.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Allocator calls made by every thread since start-up or the last [`reset_alloc_stats`].
+pub fn alloc_stats() -> AllocStats {
+    AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        deallocations: DEALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+    }
+}
+
+pub fn reset_alloc_stats() {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    DEALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+}
+
+/// Runs `f` and returns how many allocations (and bytes) the current thread made inside it.
+/// Other threads allocating at the same time, like parallel tests, don't affect the result.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let start = (THREAD_ALLOCATIONS.get(), THREAD_BYTES.get());
+    let result = f();
+    let stats = AllocStats {
+        allocations: THREAD_ALLOCATIONS.get() - start.0,
+        deallocations: 0,
+        bytes: THREAD_BYTES.get() - start.1,
+    };
+    (result, stats)
+}
+
+pub struct AllocCountExample;
+
+impl Example for AllocCountExample {
+    fn name(&self) -> &str {
+        "alloc-count"
+    }
+
+    fn description(&self) -> &str {
+        "allocations made by cloning vs borrowing"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("alloc_count.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let clones = CloneCounter::default();
+        let buffers: Vec<DeepBuffer> = (0..3)
+            .map(|i| DeepBuffer::new(&format!("buffer {}", i), &[0; 64], &clones))
+            .collect();
+
+        let (size, cloning) = measure(|| deep_clone::total_size_cloning(&buffers));
+        writeln!(
+            out,
+            "cloning:   size {}, {} allocations, {} bytes",
+            size, cloning.allocations, cloning.bytes
+        )?;
+        let (size, borrowing) = measure(|| deep_clone::total_size_borrowing(&buffers));
+        writeln!(
+            out,
+            "borrowing: size {}, {} allocations, {} bytes",
+            size, borrowing.allocations, borrowing.bytes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffers(clones: &CloneCounter) -> Vec<DeepBuffer> {
+        (0..4)
+            .map(|i| DeepBuffer::new(&format!("b{}", i), &[1; 32], clones))
+            .collect()
+    }
+
+    #[test]
+    fn borrowing_allocates_nothing() {
+        let buffers = buffers(&CloneCounter::default());
+        let (_, stats) = measure(|| deep_clone::total_size_borrowing(&buffers));
+        assert_eq!(stats.allocations, 0);
+        assert_eq!(stats.bytes, 0);
+    }
+
+    #[test]
+    fn cloning_allocates() {
+        let buffers = buffers(&CloneCounter::default());
+        let (_, stats) = measure(|| deep_clone::total_size_cloning(&buffers));
+        // the Vec itself, then the bytes and the name of each clone
+        assert!(stats.allocations >= 1);
+        assert_eq!(stats.allocations, 1 + 2 * buffers.len());
+    }
+
+    #[test]
+    fn global_counters_see_allocations() {
+        let before = alloc_stats();
+        let s = String::from("counted");
+        assert!(alloc_stats().allocations > before.allocations);
+        assert!(alloc_stats().bytes >= before.bytes + s.len());
+    }
+}
@@ -0,0 +1,177 @@
+// Arena Ownership ----------------------------------------------
+// Instead of every value having its own owner, one arena owns them all. Values handed out by the
+// arena are borrowed from it, so they can point at each other freely (even in cycles) and are all
+// dropped together when the arena goes out of scope
+use std::cell::{Cell, RefCell};
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, DropLog, Tracer};
+
+const FIRST_CHUNK: usize = 4;
+
+/// Owns every value allocated in it until the arena itself is dropped.
+///
+/// Values live in chunks that are never grown: a full chunk is left where it is and a new, bigger
+/// one is started, so a value never moves and the references handed out stay valid.
+pub struct Arena<T> {
+    chunks: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena {
+            chunks: RefCell::new(vec![Vec::with_capacity(FIRST_CHUNK)]),
+        }
+    }
+
+    /// Moves `value` into the arena and lends it back for as long as the arena lives.
+    pub fn alloc(&self, value: T) -> &T {
+        let mut chunks = self.chunks.borrow_mut();
+        let last = chunks.last().expect("there is always at least one chunk");
+        if last.len() == last.capacity() {
+            let capacity = last.capacity() * 2;
+            chunks.push(Vec::with_capacity(capacity));
+        }
+        let chunk = chunks
+            .last_mut()
+            .expect("there is always at least one chunk");
+        chunk.push(value); // fits in the capacity, so the chunk's buffer doesn't move
+        let value: *const T = chunk.last().expect("just pushed");
+        // SAFETY: the value sits in a chunk buffer that is never reallocated and never shrunk, and
+        // values are only dropped with the arena, so it is valid for as long as &self is
+        unsafe { &*value }
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many chunks are in use, more than one means the arena has grown.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+/// A word that can link to another word in the same arena.
+pub struct Word<'a> {
+    pub text: String,
+    pub next: Cell<Option<&'a Word<'a>>>,
+    _tracer: Tracer,
+}
+
+impl<'a> Word<'a> {
+    pub fn new(text: &str, log: &DropLog) -> Word<'a> {
+        Word {
+            text: text.to_string(),
+            next: Cell::new(None),
+            _tracer: Tracer::new(text, log),
+        }
+    }
+}
+
+/// Builds a ring of words in an arena, which one-owner-per-value `Box`es couldn't express.
+pub fn ring(log: &DropLog, out: &mut dyn Write) -> io::Result<()> {
+    let arena = Arena::new();
+    let words: Vec<&Word> = ["ownership", "borrowing", "lifetimes"]
+        .iter()
+        .map(|text| arena.alloc(Word::new(text, log)))
+        .collect();
+    for (i, word) in words.iter().enumerate() {
+        word.next.set(Some(words[(i + 1) % words.len()])); // the last one points back to the first
+    }
+
+    let mut word = words[0];
+    for _ in 0..4 {
+        let next = word.next.get().expect("every word is linked");
+        writeln!(out, "{} -> {}", word.text, next.text)?;
+        word = next;
+    }
+    log.borrow_mut().push(String::from("dropping the arena"));
+    Ok(())
+} // arena is dropped, taking every word with it
+
+pub struct ArenaExample;
+
+impl Example for ArenaExample {
+    fn name(&self) -> &str {
+        "arena"
+    }
+
+    fn description(&self) -> &str {
+        "one arena owns many values that borrow each other"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("arena.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let log = new_log();
+        ring(&log, out)?;
+        let events = log.borrow();
+        writeln!(out, "{}", events.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_survive_growth() {
+        let arena = Arena::new();
+        let first = arena.alloc(String::from("first"));
+        let all: Vec<&String> = (0..100).map(|i| arena.alloc(i.to_string())).collect();
+        assert!(arena.chunk_count() > 1);
+        assert_eq!(first, "first");
+        for (i, s) in all.iter().enumerate() {
+            assert_eq!(**s, i.to_string());
+        }
+        assert_eq!(arena.len(), 101);
+        assert!(!arena.is_empty());
+    }
+
+    #[test]
+    fn everything_is_dropped_once_with_the_arena() {
+        let log = new_log();
+        {
+            let arena = Arena::new();
+            for i in 0..20 {
+                arena.alloc(Tracer::new(&i.to_string(), &log));
+            }
+            assert!(!log.borrow().iter().any(|e| e.starts_with("drop")));
+        }
+        let log = log.borrow();
+        for i in 0..20 {
+            let dropped = log.iter().filter(|e| **e == format!("drop: {}", i)).count();
+            assert_eq!(dropped, 1);
+        }
+    }
+
+    #[test]
+    fn ring_links_back_to_the_start() {
+        let log = new_log();
+        let mut out = Vec::new();
+        ring(&log, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with("lifetimes -> ownership\nownership -> borrowing\n"));
+        let log = log.borrow();
+        let at = log.iter().position(|e| e == "dropping the arena").unwrap();
+        assert_eq!(log[at + 1..].len(), 3);
+    }
+}
@@ -0,0 +1,116 @@
+// Arrays and Slices --------------------------------------------
+// An array is Copy exactly when its element type is: `[i32; 5]` is copied like an i32, while
+// `[String; 3]` moves like a String. `&[T]` and `&mut [T]` borrow a view into an array or a Vec
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Like `makes_copy`: the array is copied into the function, the caller's one stays usable.
+pub fn makes_copy_array(some_array: [i32; 5], out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{:?}", some_array)
+} // some_array goes out of scope. Nothing special happens
+
+/// Like `takes_ownership`: the array and its three Strings move into the function.
+///
+/// An array of Strings isn't Copy, so assigning it moves it:
+///
+/// ```compile_fail
+/// let names = [String::from("ana"), String::from("bo")];
+/// let moved = names;
+/// println!("{:?} {:?}", names, moved); // error: borrow of moved value: `names`
+/// ```
+pub fn takes_ownership_array(some_array: [String; 3], out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{:?}", some_array)
+} // some_array goes out of scope and drops each of its Strings
+
+/// The largest element, `None` for an empty slice. The result borrows from `slice`.
+pub fn largest(slice: &[i32]) -> Option<&i32> {
+    slice.iter().max()
+}
+
+/// Sets every element of the view to `value`, the rest of the array or Vec is left alone.
+pub fn fill(slice: &mut [i32], value: i32) {
+    for x in slice {
+        *x = value;
+    }
+}
+
+pub struct ArraysExample;
+
+impl Example for ArraysExample {
+    fn name(&self) -> &str {
+        "arrays"
+    }
+
+    fn description(&self) -> &str {
+        "arrays are Copy when their elements are, slices borrow"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("arrays.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let numbers = [3, 1, 4, 1, 5];
+        makes_copy_array(numbers, out)?;
+        writeln!(out, "numbers = {:?} (copied, still usable)", numbers)?;
+        let names = [String::from("ana"), String::from("bo"), String::from("cy")];
+        takes_ownership_array(names, out)?;
+        writeln!(out, "names moved into takes_ownership_array")?;
+        writeln!(out, "largest of {:?} = {:?}", numbers, largest(&numbers))?;
+        writeln!(out, "largest of [] = {:?}", largest(&[]))?;
+        let mut grid = [0; 6];
+        fill(&mut grid[2..4], 7);
+        writeln!(out, "fill(&mut grid[2..4], 7) -> {:?}", grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrays_of_copy_types_are_copied() {
+        let mut out = Vec::new();
+        let a = [1, 2, 3, 4, 5];
+        makes_copy_array(a, &mut out).unwrap();
+        assert_eq!(out, b"[1, 2, 3, 4, 5]\n");
+        assert_eq!(a, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn largest_of_empty_and_single() {
+        assert_eq!(largest(&[]), None);
+        assert_eq!(largest(&[-4]), Some(&-4));
+        assert_eq!(largest(&[2, 9, 9, 1]), Some(&9));
+    }
+
+    #[test]
+    fn largest_borrows_from_the_slice() {
+        let v = vec![1, 8, 3];
+        let big = largest(&v).unwrap();
+        assert!(std::ptr::eq(big, &v[1]));
+    }
+
+    #[test]
+    fn fill_through_a_subslice_touches_only_that_range() {
+        let mut a = [1, 2, 3, 4, 5];
+        fill(&mut a[1..3], 0);
+        assert_eq!(a, [1, 0, 0, 4, 5]);
+        fill(&mut a[5..], 9);
+        assert_eq!(a, [1, 0, 0, 4, 5]);
+        fill(&mut a[4..], 9);
+        assert_eq!(a, [1, 0, 0, 4, 9]);
+    }
+
+    #[test]
+    fn fill_everything() {
+        let mut v = vec![1; 3];
+        fill(&mut v, 2);
+        assert_eq!(v, [2, 2, 2]);
+    }
+}
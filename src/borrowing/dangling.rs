@@ -0,0 +1,84 @@
+// Dangling Pointers --------------------------------------------
+// What the "missing lifetime specifier" and "does not live long enough" errors protect against.
+// A raw pointer isn't checked by the borrow checker, so it can outlive the String it points into.
+// Creating such a pointer is safe, reading through it is undefined behaviour, which is why this
+// module only exists with the `unsafe-demos` feature and the default path never dereferences it
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Returns a pointer into a String that has already been dropped.
+///
+/// This is what `fn dangle() -> &String` would have handed out if the borrow checker allowed it.
+pub fn dangling_raw() -> *const u8 {
+    let s = String::from("hello");
+    let ptr = s.as_ptr();
+    drop(s); // the heap buffer is freed, ptr still holds its old address
+    ptr
+}
+
+/// The version the borrow checker accepts: ownership of the String moves out to the caller.
+pub fn safe_alternative() -> String {
+    String::from("hello")
+}
+
+pub struct DanglingExample;
+
+impl Example for DanglingExample {
+    fn name(&self) -> &str {
+        "dangling"
+    }
+
+    fn description(&self) -> &str {
+        "the dangling pointer a reference can never be"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("dangling.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let ptr = dangling_raw();
+        writeln!(out, "dangling_raw() = {:p}", ptr)?;
+        writeln!(out, "  the String it pointed into is gone, the allocator may already have reused the memory")?;
+        writeln!(
+            out,
+            "  reading *ptr would be undefined behaviour, so it is never done here"
+        )?;
+        writeln!(
+            out,
+            "safe_alternative() = {:?}, moved out and still owned",
+            safe_alternative()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // no unsafe here, so this passes under `cargo miri test` too
+    #[test]
+    fn safe_alternative_moves_ownership_out() {
+        let s = safe_alternative();
+        assert_eq!(s, "hello");
+        assert!(!dangling_raw().is_null()); // the address survives, the memory behind it doesn't
+    }
+
+    /// UNDEFINED BEHAVIOUR: reads through a pointer whose String has been dropped. The read may
+    /// return "hello", garbage or crash, and the optimizer is allowed to assume it never happens.
+    /// `cargo miri test --features unsafe-demos -- --ignored` reports it as a use-after-free.
+    #[cfg(feature = "unsafe-demos")]
+    #[test]
+    #[ignore = "undefined behaviour on purpose, run under Miri"]
+    fn dereferencing_the_dangling_pointer_is_ub() {
+        let ptr = dangling_raw();
+        // SAFETY: none, ptr points into freed memory. This is the bug the borrow checker prevents
+        let first = unsafe { *ptr };
+        let _ = first;
+    }
+}
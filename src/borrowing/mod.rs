@@ -0,0 +1,6 @@
+// Borrowing: using a value through a reference without taking ownership of it
+#[cfg(feature = "unsafe-demos")]
+pub mod dangling;
+pub mod mutable;
+pub mod shared;
+pub mod split;
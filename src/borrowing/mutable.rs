@@ -0,0 +1,126 @@
+// Mutable References -------------------------------------------
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+pub fn change(some_string: &mut String) {
+    some_string.push_str(", world");
+} // some_string is mutable, so the value can be changed
+// mutable reference have one big restriction: you can only have one mutable reference to a particular piece of data in a particular scope
+
+/// Like [`change`], but hands the same mutable reference back so calls can be chained.
+///
+/// Every result is the one `&mut s`, so two of them can't be held at the same time:
+///
+/// ```compile_fail
+/// use ownership::borrowing::mutable::change_chained;
+///
+/// let mut s = String::from("hello");
+/// let first = change_chained(&mut s);
+/// let second = change_chained(&mut s); // error: cannot borrow `s` as mutable more than once at a time
+/// first.push('!');
+/// second.push('?');
+/// ```
+pub fn change_chained(some_string: &mut String) -> &mut String {
+    some_string.push_str(", world");
+    some_string
+}
+
+/// Method-style chaining on `String` through an extension trait.
+pub trait StringExt {
+    /// Uppercases in place and appends `!`.
+    fn shout(&mut self) -> &mut Self;
+}
+
+impl StringExt for String {
+    fn shout(&mut self) -> &mut Self {
+        self.make_ascii_uppercase();
+        self.push('!');
+        self
+    }
+}
+
+/// Takes two mutable references one after the other, each in its own scope.
+pub fn sequential_borrows() -> String {
+    let mut str_3 = String::from("hello");
+    {
+        let r1 = &mut str_3;
+        r1.push('!');
+    } // r1 goes out of scope here, so we can make a new reference with no problems
+    let r2 = &mut str_3;
+    r2.push('!');
+    str_3
+}
+
+// This code is error:
+// let mut string_1 = String::from("hello");
+// let refer_1 = &string_1; // no problem
+// let refer2 = &string_1; // no problem
+// let refer3 = &mut string_1; // BIG PROBLEM
+// can't borrow as mutable because it is also borrowed as immutable
+// These mistakes are compiled for real in tests/compile_fail/ (shared_and_mut_borrow.rs, two_mut_borrows.rs)
+// and `cargo run -- puzzles` asks how to fix them
+
+pub struct MutableReferencesExample;
+
+impl Example for MutableReferencesExample {
+    fn name(&self) -> &str {
+        "mutable-references"
+    }
+
+    fn description(&self) -> &str {
+        "only one mutable reference at a time"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mutable.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut str_2 = String::from("hello");
+        change(&mut str_2);
+        writeln!(out, "str_2 = {}", str_2)?;
+        let mut chained = String::from("hello");
+        change_chained(change_chained(&mut chained)).push('!');
+        writeln!(out, "chained = {}", chained)?;
+        let mut loud = String::from("hello");
+        loud.shout().shout();
+        writeln!(out, "loud = {}", loud)?;
+        writeln!(out, "str_3 = {}", sequential_borrows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_appends_through_the_mutable_reference() {
+        let mut s = String::from("hello");
+        change(&mut s);
+        assert_eq!(s, "hello, world");
+    }
+
+    #[test]
+    fn change_chained_applies_every_mutation() {
+        let mut s = String::from("hello");
+        change_chained(change_chained(change_chained(&mut s)));
+        assert_eq!(s, "hello, world, world, world");
+    }
+
+    #[test]
+    fn shout_chains_method_style() {
+        let mut s = String::from("hi");
+        s.shout().shout().push_str(" there");
+        assert_eq!(s, "HI!! there");
+    }
+
+    #[test]
+    fn mutable_borrows_in_separate_scopes_are_allowed() {
+        assert_eq!(sequential_borrows(), "hello!!");
+    }
+}
@@ -0,0 +1,210 @@
+// References and Borrowing -------------------------------------
+use std::io::{self, Write};
+
+use crate::demo_result::{Action, DemoResult};
+use crate::example::{Example, Rule, RunConfig};
+
+// & is a reference, which allows you to refer to some value without taking ownership of it
+#[allow(clippy::ptr_arg)] // &String on purpose, this is the first reference learners see
+pub fn calculate_length_ref(s: &String) -> usize {
+    s.len()
+} // s goes out of scope, but because it does not have ownership of what it refers to, nothing happens
+// Is you try to modify while borrowing, you will get a compile error
+// As variables are immutable by default, so are references. You can make them mutable by using &mut
+
+/// The idiomatic signature: `&str` accepts borrowed Strings, literals and slices alike.
+///
+/// It still borrows, so an owned `String` can't be passed by value:
+///
+/// ```compile_fail
+/// use ownership::borrowing::shared::calculate_length_str;
+///
+/// let s = String::from("hello");
+/// calculate_length_str(s); // error: mismatched types, expected `&str`, found `String`
+/// ```
+pub fn calculate_length_str(s: &str) -> usize {
+    s.len()
+}
+
+/// One signature for owning and borrowing callers: anything that can be viewed as a `&str`.
+///
+/// Unlike `calculate_length(s: String) -> (String, usize)`, a borrowing caller doesn't need to
+/// get the String handed back. An owning caller still gives it up though:
+///
+/// ```compile_fail
+/// use ownership::borrowing::shared::calculate_length_generic;
+///
+/// let s = String::from("hello");
+/// calculate_length_generic(s); // s is moved into the function and dropped there
+/// println!("{}", s); // error: borrow of moved value: `s`
+/// ```
+pub fn calculate_length_generic<S: AsRef<str>>(s: S) -> usize {
+    s.as_ref().len()
+}
+
+/// Calls [`calculate_length_generic`] with an owned String, a `&String`, a `&str` and a `Box<str>`.
+pub fn generic_lengths(out: &mut dyn Write) -> io::Result<()> {
+    let borrowed = String::from("hello");
+    writeln!(
+        out,
+        "fn calculate_length_generic<S: AsRef<str>>(s: S) -> usize"
+    )?;
+    writeln!(
+        out,
+        "  String:   {} (moved in)",
+        calculate_length_generic(String::from("hello"))
+    )?;
+    writeln!(
+        out,
+        "  &String:  {} (still usable: {})",
+        calculate_length_generic(&borrowed),
+        borrowed
+    )?;
+    writeln!(out, "  &str:     {}", calculate_length_generic("hello"))?;
+    let boxed: Box<str> = Box::from("hello");
+    writeln!(
+        out,
+        "  Box<str>: {} (moved in)",
+        calculate_length_generic(boxed)
+    )
+}
+
+// Deref Coercion -----------------------------------------------
+// String implements Deref<Target = str>, so the compiler turns a &String into a &str at the call
+// site by itself. There is no way back: a &str may point into a literal or a slice, there is no
+// String behind it to hand out
+
+/// Calls both length functions and prints their signatures to explain why `&str` is preferred.
+///
+/// The reverse coercion doesn't exist:
+///
+/// ```compile_fail
+/// use ownership::borrowing::shared::calculate_length_ref;
+///
+/// calculate_length_ref("hello"); // error: mismatched types, expected `&String`, found `&str`
+/// ```
+pub fn deref_coercion(out: &mut dyn Write) -> io::Result<()> {
+    let owned = String::from("hello world");
+    writeln!(out, "fn calculate_length_ref(s: &String) -> usize")?;
+    writeln!(out, "  &String:     {}", calculate_length_ref(&owned))?;
+    writeln!(out, "fn calculate_length_str(s: &str) -> usize")?;
+    writeln!(
+        out,
+        "  &String:     {} (coerced to &str)",
+        calculate_length_str(&owned)
+    )?;
+    writeln!(out, "  literal:     {}", calculate_length_str("hello"))?;
+    writeln!(out, "  &owned[..5]: {}", calculate_length_str(&owned[..5]))?;
+    writeln!(out, "&str takes everything &String takes, and more")
+}
+
+// Dangling References -------------------------------------------
+// Rust ensures that references will never be dangling references, which would be a pointer to memory that may have been given to someone else, by ensuring that all borrows are valid
+// See tests/compile_fail/dangling_reference.rs for the function that tries to return one, and
+// borrowing::dangling (feature `unsafe-demos`) for the raw pointer that shows what it would return
+
+/// Borrows a String for [`calculate_length_ref`], recording the borrow, then shows the coercions.
+pub fn demo() -> io::Result<DemoResult> {
+    let mut result = DemoResult::new("references");
+    let str_1 = String::from("hello");
+    result.step(Action::Create, &["str_1"]);
+    let len = calculate_length_ref(&str_1);
+    result.step(Action::Borrow, &["str_1", "s"]);
+    writeln!(result, "The length of '{}' is {}", str_1, len)?;
+    deref_coercion(&mut result)?;
+    generic_lengths(&mut result)?;
+    drop(str_1); // it was only borrowed, so it is still ours to drop
+    result.step(Action::Drop, &["str_1"]);
+    Ok(result)
+}
+
+pub struct ReferencesExample;
+
+impl Example for ReferencesExample {
+    fn name(&self) -> &str {
+        "references"
+    }
+
+    fn description(&self) -> &str {
+        "borrowing a value without taking ownership"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("shared.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        demo()?.render(out)
+    }
+
+    fn result(&self, _config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        demo().map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowing_leaves_the_owner_usable() {
+        let s = String::from("hello");
+        assert_eq!(calculate_length_ref(&s), 5);
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn str_version_accepts_a_borrowed_string() {
+        let s = String::from("hello");
+        assert_eq!(calculate_length_str(&s), 5);
+        assert_eq!(calculate_length_str(&s), calculate_length_ref(&s));
+    }
+
+    #[test]
+    fn generic_version_accepts_an_owned_string() {
+        assert_eq!(calculate_length_generic(String::from("hello")), 5);
+    }
+
+    #[test]
+    fn generic_version_accepts_a_borrowed_string() {
+        let s = String::from("hello");
+        assert_eq!(calculate_length_generic(&s), 5);
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn generic_version_accepts_a_str() {
+        assert_eq!(calculate_length_generic("héllo"), 6);
+    }
+
+    #[test]
+    fn generic_version_accepts_a_boxed_str() {
+        let boxed: Box<str> = Box::from("hello");
+        assert_eq!(calculate_length_generic(boxed), 5);
+    }
+
+    #[test]
+    fn str_version_accepts_a_literal() {
+        assert_eq!(calculate_length_str("hello"), 5);
+    }
+
+    #[test]
+    fn str_version_accepts_a_slice_of_a_string() {
+        let s = String::from("hello");
+        assert_eq!(calculate_length_str(&s[..3]), 3);
+    }
+
+    #[test]
+    fn demo_borrows_without_moving() {
+        let result = demo().unwrap();
+        let borrows: Vec<_> = result.steps_of(Action::Borrow).collect();
+        assert_eq!(borrows.len(), 1);
+        assert_eq!(borrows[0].vars, ["str_1", "s"]);
+        assert_eq!(result.steps_of(Action::Move).count(), 0);
+        assert!(result.output.starts_with("The length of 'hello' is 5\n"));
+    }
+}
@@ -0,0 +1,135 @@
+// Split Borrows ------------------------------------------------
+// `&mut v[0]` and `&mut v[1]` never overlap, but both borrow all of `v`: the borrow checker
+// reasons about the binding, not the elements. split_at_mut, iter_mut and slice patterns hand
+// out disjoint mutable references it can check
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Swaps the first half of `v` with the last half, the middle element of an odd length stays put.
+///
+/// Indexing twice borrows `v` mutably twice, even though the elements are different:
+///
+/// ```compile_fail
+/// let mut v = vec![1, 2];
+/// let a = &mut v[0];
+/// let b = &mut v[1]; // error: cannot borrow `v` as mutable more than once at a time
+/// std::mem::swap(a, b);
+/// ```
+#[allow(clippy::ptr_arg)] // the Vec is the point, split_at_mut comes from the slice it derefs to
+pub fn swap_halves(v: &mut Vec<i32>) {
+    let half = v.len() / 2;
+    let at = v.len() - half;
+    let (front, back) = v.split_at_mut(at);
+    front[..half].swap_with_slice(back);
+}
+
+/// Adds one to every element, each `&mut` from iter_mut borrows a single element.
+pub fn increment_all(v: &mut [i64]) {
+    for x in v.iter_mut() {
+        *x += 1;
+    }
+}
+
+/// Swaps the first two elements through a slice pattern, doing nothing if there are fewer.
+pub fn swap_first_two(v: &mut [i32]) {
+    if let [a, b, ..] = v {
+        std::mem::swap(a, b);
+    }
+}
+
+pub struct SplitBorrowsExample;
+
+impl Example for SplitBorrowsExample {
+    fn name(&self) -> &str {
+        "split-borrows"
+    }
+
+    fn description(&self) -> &str {
+        "two mutable borrows of one Vec, done right"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("split.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        writeln!(
+            out,
+            "`&mut v[0]` and `&mut v[1]` both borrow v: the borrow checker reasons per binding, not per element"
+        )?;
+        let mut v = vec![1, 2, 3, 4, 5];
+        {
+            let (left, right) = v.split_at_mut(2);
+            left[0] += right[0];
+            writeln!(out, "split_at_mut(2) gives {:?} and {:?}", left, right)?;
+        }
+        swap_halves(&mut v);
+        writeln!(out, "swap_halves -> {:?}", v)?;
+        swap_first_two(&mut v);
+        writeln!(out, "if let [a, b, ..] swapped a and b -> {:?}", v)?;
+        let mut counts = [0_i64, 10, 20];
+        increment_all(&mut counts);
+        writeln!(
+            out,
+            "iter_mut hands out one &mut per element -> {:?}",
+            counts
+        )?;
+        writeln!(
+            out,
+            "still one mutable reference per piece of data, the pieces are just smaller"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swapped(mut v: Vec<i32>) -> Vec<i32> {
+        swap_halves(&mut v);
+        v
+    }
+
+    #[test]
+    fn swap_halves_even_length() {
+        assert_eq!(swapped(vec![1, 2, 3, 4]), [3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn swap_halves_keeps_the_middle_of_odd_lengths() {
+        assert_eq!(swapped(vec![1, 2, 3, 4, 5]), [4, 5, 3, 1, 2]);
+        assert_eq!(swapped(vec![7]), [7]);
+    }
+
+    #[test]
+    fn swap_halves_of_nothing() {
+        assert_eq!(swapped(Vec::new()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn increment_all_touches_every_element() {
+        let mut v = [1, -1, 41];
+        increment_all(&mut v);
+        assert_eq!(v, [2, 0, 42]);
+        let mut odd = [0, 0, 0, 0, 0];
+        increment_all(&mut odd);
+        assert_eq!(odd, [1; 5]);
+        let mut empty: [i64; 0] = [];
+        increment_all(&mut empty);
+    }
+
+    #[test]
+    fn swap_first_two_needs_two() {
+        let mut v = [1, 2, 3];
+        swap_first_two(&mut v);
+        assert_eq!(v, [2, 1, 3]);
+        let mut one = [1];
+        swap_first_two(&mut one);
+        assert_eq!(one, [1]);
+    }
+}
@@ -0,0 +1,125 @@
+// Box<T>: Heap Allocation for Any Type ---------------------------
+// Box is the simplest smart pointer: the Box itself (a pointer) lives on the stack, the value it
+// owns lives on the heap. Like a String, the heap data is freed when the Box goes out of scope
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// A cons list: each element owns the rest of the list through a `Box`.
+///
+/// Without the `Box` the compiler can't figure out how big a `List` is, it would contain itself:
+///
+/// ```compile_fail
+/// enum List {
+///     Cons(i32, List), // error: recursive type `List` has infinite size
+///     Nil,
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    pub fn nil() -> List {
+        Nil
+    }
+
+    /// Prepends `value`, taking ownership of `tail`.
+    pub fn cons(value: i32, tail: List) -> List {
+        Cons(value, Box::new(tail))
+    }
+
+    /// Builds a list with the same elements, in the same order, as `values`.
+    pub fn from_slice(values: &[i32]) -> List {
+        values
+            .iter()
+            .rev()
+            .fold(Nil, |tail, &v| List::cons(v, tail))
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Cons(_, tail) => 1 + tail.len(),
+            Nil => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Nil)
+    }
+
+    pub fn sum(&self) -> i32 {
+        match self {
+            Cons(value, tail) => value + tail.sum(),
+            Nil => 0,
+        }
+    }
+}
+
+pub struct BoxExample;
+
+impl Example for BoxExample {
+    fn name(&self) -> &str {
+        "box"
+    }
+
+    fn description(&self) -> &str {
+        "owning heap allocated values of any type"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("box_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let b = Box::new(5);
+        writeln!(out, "b = {}", b)?; // Box<i32> implements Display through Deref
+        writeln!(
+            out,
+            "the pointer is on the stack ({} bytes), the i32 is on the heap at {:p}",
+            std::mem::size_of_val(&b),
+            b
+        )?;
+
+        let list = List::cons(1, List::cons(2, List::cons(3, List::nil())));
+        writeln!(out, "list = {:?}", list)?;
+        writeln!(out, "len = {}, sum = {}", list.len(), list.sum())
+    } // b and list go out of scope, every Box in the chain is freed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_element_list() {
+        let list = List::cons(1, List::cons(2, List::cons(3, List::nil())));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.sum(), 6);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn empty_list() {
+        let list = List::nil();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.sum(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn from_slice_keeps_the_order() {
+        assert_eq!(
+            List::from_slice(&[1, 2, 3]),
+            List::cons(1, List::cons(2, List::cons(3, List::nil())))
+        );
+    }
+}
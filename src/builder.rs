@@ -0,0 +1,237 @@
+// Builders: Consuming vs Borrowing -----------------------------
+// A builder whose methods take `self` moves itself through the chain and is used up by build(), so
+// a half-configured builder can't be reused by accident. One whose methods take `&mut self` stays
+// with the caller and can build many values, at the cost of cloning its fields each time
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BuildError {
+    /// `host` was never set, there is no sensible default for it.
+    MissingHost,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingHost => write!(f, "a request needs a host"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builder that moves itself through every step.
+///
+/// `build` consumes the builder, so it can't be used again afterwards:
+///
+/// ```compile_fail
+/// use ownership::builder::RequestBuilder;
+///
+/// let builder = RequestBuilder::new().host("example.com");
+/// let first = builder.build();
+/// let second = builder.port(8080).build(); // error: use of moved value: `builder`
+/// ```
+#[derive(Debug)]
+pub struct RequestBuilder {
+    host: Option<String>,
+    port: u16,
+    path: String,
+}
+
+impl RequestBuilder {
+    pub fn new() -> RequestBuilder {
+        RequestBuilder {
+            host: None,
+            port: 80,
+            path: String::from("/"),
+        }
+    }
+
+    pub fn host(mut self, h: &str) -> Self {
+        self.host = Some(h.to_string());
+        self
+    }
+
+    pub fn port(mut self, p: u16) -> Self {
+        self.port = p;
+        self
+    }
+
+    pub fn path(mut self, p: &str) -> Self {
+        self.path = p.to_string();
+        self
+    }
+
+    /// Moves the fields into the Request, nothing is cloned.
+    pub fn build(self) -> Result<Request, BuildError> {
+        Ok(Request {
+            host: self.host.ok_or(BuildError::MissingHost)?,
+            port: self.port,
+            path: self.path,
+        })
+    }
+}
+
+impl Default for RequestBuilder {
+    fn default() -> RequestBuilder {
+        RequestBuilder::new()
+    }
+}
+
+/// Builder that is borrowed by every step and stays usable after `build`.
+///
+/// ```
+/// use ownership::builder::RequestOptions;
+///
+/// let mut options = RequestOptions::new();
+/// options.host("example.com");
+/// let first = options.build().unwrap();
+/// let second = options.port(8080).build().unwrap(); // options is still ours
+/// assert_eq!((first.port, second.port), (80, 8080));
+/// ```
+#[derive(Debug, Default)]
+pub struct RequestOptions {
+    host: Option<String>,
+    port: Option<u16>,
+    path: Option<String>,
+}
+
+impl RequestOptions {
+    pub fn new() -> RequestOptions {
+        RequestOptions::default()
+    }
+
+    pub fn host(&mut self, h: &str) -> &mut Self {
+        self.host = Some(h.to_string());
+        self
+    }
+
+    pub fn port(&mut self, p: u16) -> &mut Self {
+        self.port = Some(p);
+        self
+    }
+
+    pub fn path(&mut self, p: &str) -> &mut Self {
+        self.path = Some(p.to_string());
+        self
+    }
+
+    /// Clones the fields into a Request, the options only lend them.
+    pub fn build(&self) -> Result<Request, BuildError> {
+        Ok(Request {
+            host: self.host.clone().ok_or(BuildError::MissingHost)?,
+            port: self.port.unwrap_or(80),
+            path: self.path.clone().unwrap_or_else(|| String::from("/")),
+        })
+    }
+}
+
+pub struct BuilderExample;
+
+impl Example for BuilderExample {
+    fn name(&self) -> &str {
+        "builder"
+    }
+
+    fn description(&self) -> &str {
+        "builders that consume self vs borrow &mut self"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("builder.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let request = RequestBuilder::new().host("example.com").port(8080).build();
+        writeln!(out, "consuming: {:?}", request)?;
+        writeln!(
+            out,
+            "consuming, no host: {:?}",
+            RequestBuilder::new().build()
+        )?;
+
+        let mut options = RequestOptions::new();
+        options.host("example.com");
+        for path in ["/a", "/b"] {
+            let request = options.path(path).build(); // the same options, built twice
+            writeln!(out, "borrowing: {:?}", request)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_fill_in_optional_fields() {
+        let expected = Request {
+            host: String::from("h"),
+            port: 80,
+            path: String::from("/"),
+        };
+        assert_eq!(
+            RequestBuilder::new().host("h").build(),
+            Ok(expected.clone())
+        );
+        assert_eq!(RequestOptions::new().host("h").build(), Ok(expected));
+    }
+
+    #[test]
+    fn last_setting_wins() {
+        let request = RequestBuilder::new()
+            .host("a")
+            .host("b")
+            .port(1)
+            .port(2)
+            .build()
+            .unwrap();
+        assert_eq!((request.host.as_str(), request.port), ("b", 2));
+
+        let mut options = RequestOptions::new();
+        options.host("a").path("/x").path("/y");
+        let request = options.build().unwrap();
+        assert_eq!((request.host.as_str(), request.path.as_str()), ("a", "/y"));
+    }
+
+    #[test]
+    fn missing_host_is_an_error() {
+        assert_eq!(
+            RequestBuilder::new().port(1).build(),
+            Err(BuildError::MissingHost)
+        );
+        assert_eq!(
+            RequestOptions::new().path("/").build(),
+            Err(BuildError::MissingHost)
+        );
+        assert_eq!(
+            BuildError::MissingHost.to_string(),
+            "a request needs a host"
+        );
+    }
+
+    #[test]
+    fn borrowing_builder_can_build_repeatedly() {
+        let mut options = RequestOptions::new();
+        options.host("h");
+        let first = options.build().unwrap();
+        let second = options.port(9).build().unwrap();
+        assert_eq!((first.port, second.port), (80, 9));
+        assert_eq!(first.host, second.host);
+    }
+}
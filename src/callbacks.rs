@@ -0,0 +1,111 @@
+// Function Pointers vs Closures --------------------------------
+// A `fn(String) -> String` is only code, so it can't carry anything from its surroundings: named
+// functions and closures that capture nothing coerce to it. A generic `F: FnOnce` also accepts
+// closures that own what they captured. Either way the String moves in and the result moves out
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Hands `s` to `f` and gives back what `f` returns.
+///
+/// A closure that captures a variable isn't a plain function pointer:
+///
+/// ```compile_fail
+/// use ownership::callbacks::apply_fn;
+///
+/// let suffix = String::from("!");
+/// apply_fn(String::from("hi"), move |s| s + &suffix); // error: mismatched types, closures can only be coerced to `fn` types if they do not capture any variables
+/// ```
+pub fn apply_fn(s: String, f: fn(String) -> String) -> String {
+    f(s)
+}
+
+/// Hands `s` to `f`, which may own captured values and use them up, since it is called once.
+pub fn apply_closure<F: FnOnce(String) -> String>(s: String, f: F) -> String {
+    f(s)
+}
+
+pub fn shout(s: String) -> String {
+    s.to_uppercase()
+}
+
+pub struct CallbacksExample;
+
+impl Example for CallbacksExample {
+    fn name(&self) -> &str {
+        "callbacks"
+    }
+
+    fn description(&self) -> &str {
+        "fn pointers can't capture, closures can"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("callbacks.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        writeln!(
+            out,
+            "apply_fn(\"hello\", shout) = {:?}",
+            apply_fn(String::from("hello"), shout)
+        )?;
+        writeln!(
+            out,
+            "apply_fn with a non-capturing closure = {:?}",
+            apply_fn(String::from("hello"), |s| s.chars().rev().collect())
+        )?;
+        let suffix = String::from(", world");
+        let result = apply_closure(String::from("hello"), move |mut s| {
+            s.push_str(&suffix);
+            drop(suffix); // the closure owns suffix, and uses it up
+            s
+        });
+        writeln!(
+            out,
+            "apply_closure with a captured suffix = {:?}, suffix was moved into the closure",
+            result
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_function_as_fn_pointer() {
+        assert_eq!(apply_fn(String::from("abc"), shout), "ABC");
+    }
+
+    #[test]
+    fn non_capturing_closure_coerces_to_fn_pointer() {
+        let f: fn(String) -> String = |s| s + "?";
+        assert_eq!(apply_fn(String::from("why"), f), "why?");
+        assert_eq!(apply_fn(String::from("x"), |s| s.repeat(2)), "xx");
+    }
+
+    #[test]
+    fn capturing_closure_through_the_generic_version() {
+        let suffix = String::from("!");
+        assert_eq!(
+            apply_closure(String::from("hi"), move |s| s + &suffix),
+            "hi!"
+        );
+        assert_eq!(apply_closure(String::from("hi"), shout), "HI");
+    }
+
+    #[test]
+    fn the_string_moves_through_without_copying() {
+        let s = String::from("buffer");
+        let ptr = s.as_ptr();
+        let back = apply_fn(s, |s| s);
+        assert_eq!(back.as_ptr(), ptr);
+        let back = apply_closure(back, |s| s);
+        assert_eq!(back.as_ptr(), ptr);
+    }
+}
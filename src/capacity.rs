@@ -0,0 +1,180 @@
+// Capacity -----------------------------------------------------
+// A String that outgrows its buffer allocates a bigger one, copies the bytes over and frees the
+// old one. Knowing the final size up front makes that a single allocation, and shrink_to_fit hands
+// back what is no longer needed
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// How often a String grew while it was being built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Growth {
+    /// Times the capacity changed, each one a call to the allocator.
+    reallocations: usize,
+    /// Times the buffer ended up at a new address. At most `reallocations`, because the
+    /// allocator can sometimes grow a buffer in place, and how often depends on the allocator.
+    moves: usize,
+}
+
+/// Appends every word to `s`, watching its capacity and its pointer.
+fn build(mut s: String, words: &[&str]) -> (String, Growth) {
+    let mut growth = Growth {
+        reallocations: 0,
+        moves: 0,
+    };
+    let (mut capacity, mut ptr) = (s.capacity(), s.as_ptr());
+    for word in words {
+        s.push_str(word);
+        if s.capacity() != capacity {
+            growth.reallocations += 1;
+            capacity = s.capacity();
+        }
+        if s.as_ptr() != ptr {
+            growth.moves += 1;
+            ptr = s.as_ptr();
+        }
+    }
+    (s, growth)
+}
+
+/// Concatenates `words` into a String allocated once, at exactly the size it needs.
+pub fn build_exact(words: &[&str]) -> String {
+    let len = words.iter().map(|w| w.len()).sum();
+    let (s, growth) = build(String::with_capacity(len), words);
+    // the buffer was big enough from the start, so the pointer never changed
+    assert_eq!(growth.moves, 0, "build_exact reallocated");
+    s
+}
+
+/// Concatenates `words` into a String that starts empty, returning how often its buffer moved.
+pub fn build_naive(words: &[&str]) -> (String, usize) {
+    let (s, growth) = build(String::new(), words);
+    (s, growth.moves)
+}
+
+/// Keeps the first `keep` bytes of `s` and gives the spare capacity back to the allocator.
+/// Panics if `keep` isn't on a char boundary, like `String::truncate`.
+pub fn shrink(mut s: String, keep: usize) -> String {
+    s.truncate(keep);
+    s.shrink_to_fit();
+    s
+}
+
+pub struct CapacityExample;
+
+impl Example for CapacityExample {
+    fn name(&self) -> &str {
+        "capacity"
+    }
+
+    fn description(&self) -> &str {
+        "with_capacity, reserve and shrink_to_fit"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("capacity.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let words = [
+            "own",
+            "ership ",
+            "moves, ",
+            "borrows ",
+            "and drops ",
+            "values",
+        ]
+        .repeat(4);
+        let len = words.iter().map(|w| w.len()).sum();
+        let (naive, naive_growth) = build(String::new(), &words);
+        let (exact, exact_growth) = build(String::with_capacity(len), &words);
+        writeln!(out, "         reallocations  capacity  len")?;
+        for (label, s, growth) in [
+            ("naive", &naive, naive_growth),
+            ("exact", &exact, exact_growth),
+        ] {
+            writeln!(
+                out,
+                "{:<5}    {:>13}  {:>8}  {:>3}",
+                label,
+                growth.reallocations,
+                s.capacity(),
+                s.len()
+            )?;
+        }
+        writeln!(
+            out,
+            "the naive buffer moved at most {} times, realloc can sometimes grow it in place",
+            naive_growth.reallocations
+        )?;
+        let mut reserved = String::from("abc");
+        reserved.reserve(100);
+        writeln!(
+            out,
+            "reserve(100) on {:?}: capacity at least {}",
+            reserved,
+            reserved.len() + 100
+        )?;
+        let before = naive.capacity();
+        let shrunk = shrink(naive, 3);
+        writeln!(
+            out,
+            "shrink to {:?}: capacity {} -> {}",
+            shrunk,
+            before,
+            shrunk.capacity()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn many_words() -> Vec<&'static str> {
+        ["a", "bc", "def", "ghij"].repeat(50)
+    }
+
+    #[test]
+    fn build_exact_never_moves_its_buffer() {
+        let words = many_words();
+        let s = build_exact(&words);
+        assert_eq!(s.len(), 500);
+        assert_eq!(s.capacity(), s.len());
+        assert_eq!(build_exact(&[]), "");
+    }
+
+    #[test]
+    fn build_naive_reallocates_for_enough_input() {
+        let words = many_words();
+        let (s, moves) = build_naive(&words);
+        assert_eq!(s, build_exact(&words));
+        assert!(moves >= 1, "{} reallocations", moves);
+    }
+
+    #[test]
+    fn moves_never_outnumber_reallocations() {
+        let (_, growth) = build(String::new(), &many_words());
+        assert!(growth.moves <= growth.reallocations);
+        assert!(growth.reallocations >= 1);
+    }
+
+    #[test]
+    fn nothing_to_build_means_no_reallocation() {
+        assert_eq!(build_naive(&[]), (String::new(), 0));
+    }
+
+    #[test]
+    fn shrink_brings_capacity_down_to_len() {
+        let mut s = String::with_capacity(1024);
+        s.push_str("hello, world");
+        let s = shrink(s, 5);
+        assert_eq!(s, "hello");
+        assert!(s.capacity() <= s.len());
+        assert_eq!(shrink(String::from("hi"), 10), "hi");
+    }
+}
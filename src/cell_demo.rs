@@ -0,0 +1,111 @@
+// Interior Mutability: Cell<T> -----------------------------------
+// Cell is the Copy-type sibling of RefCell: instead of lending out references it copies values in
+// and out (get, set, replace), so mutating through a shared reference needs no borrow tracking
+use std::cell::Cell;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Counts calls to `record`, even though `record` only takes `&self`.
+///
+/// With a plain `u32` field the same method doesn't compile, `&self` is read-only:
+///
+/// ```compile_fail
+/// struct Counter {
+///     hits: u32,
+/// }
+///
+/// impl Counter {
+///     fn record(&self) {
+///         self.hits += 1; // error: cannot assign to `self.hits`, which is behind a `&` reference
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Counter {
+    hits: Cell<u32>,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter::default()
+    }
+
+    pub fn record(&self) {
+        self.hits.set(self.hits.get() + 1);
+    }
+
+    pub fn hits(&self) -> u32 {
+        self.hits.get()
+    }
+}
+
+pub struct CellExample;
+
+impl Example for CellExample {
+    fn name(&self) -> &str {
+        "cell"
+    }
+
+    fn description(&self) -> &str {
+        "mutating Copy values through a shared reference"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("cell_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let cell = Cell::new(5);
+        let shared = &cell; // a plain &i32 could only be read
+        writeln!(out, "get:     {}", shared.get())?;
+        shared.set(6);
+        writeln!(out, "set(6):  {}", cell.get())?;
+        let old = shared.replace(7);
+        writeln!(out, "replace(7) returned {}, now {}", old, cell.get())?;
+
+        let counter = Counter::new();
+        let (a, b) = (&counter, &counter);
+        a.record();
+        b.record();
+        counter.record();
+        writeln!(
+            out,
+            "counter recorded {} hits through three aliases",
+            counter.hits()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_counts_through_every_alias() {
+        let counter = Counter::new();
+        let aliases = [&counter, &counter, &counter];
+        for alias in aliases {
+            alias.record();
+            alias.record();
+        }
+        assert_eq!(counter.hits(), 6);
+    }
+
+    #[test]
+    fn new_counter_starts_at_zero() {
+        assert_eq!(Counter::new().hits(), 0);
+    }
+
+    #[test]
+    fn replace_returns_the_old_value() {
+        let cell = Cell::new(1);
+        let alias = &cell;
+        assert_eq!(alias.replace(2), 1);
+        assert_eq!(cell.get(), 2);
+    }
+}
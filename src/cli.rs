@@ -0,0 +1,703 @@
+// Command line dispatch: `cargo run` runs every example, `cargo run -- <name>` runs a single one,
+// `cargo run -- --list` prints what is available and `cargo run -- quiz [--file <path>]` starts the
+// quiz, `--shuffle [--seed <n>]` mixing up its questions. `cargo run -- puzzles` asks how to fix
+// snippets the borrow checker rejects.
+// Every run is recorded in the progress file, `--progress-file <path>` picks another one, and
+// `--color=auto|always|never` controls the styling of banners. `-v`/`--verbose` makes the examples
+// trace every String they change. `cargo run -- --exercises` lists the exercises to solve, and
+// `--format json` prints examples and their results as JSON instead of text.
+// `cargo run -- export [--out <dir>]` writes a Markdown page per example, to docs/ by default.
+// Running everything ends with a table of how long each example took
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::example::{find, registry, title, Example, RunConfig};
+use crate::exercises::EXERCISES;
+use crate::export;
+use crate::json::{self, ExampleInfo, Versioned};
+use crate::output::{ColorChoice, Style};
+use crate::progress::{self, Progress};
+use crate::puzzles;
+use crate::quiz;
+use crate::report::{self, RunReport};
+use crate::rng::XorShift;
+
+#[derive(Debug)]
+pub enum CliError {
+    /// No example is registered under this name.
+    UnknownExample {
+        name: String,
+        valid: Vec<String>,
+    },
+    /// The quiz questions couldn't be loaded.
+    Quiz(quiz::QuizError),
+    /// An option is missing its value, e.g. `quiz --file` without a path.
+    MissingValue(&'static str),
+    /// An option's value isn't one it accepts.
+    InvalidValue(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownExample { name, valid } => {
+                write!(f, "unknown example `{}`, valid examples are:", name)?;
+                for v in valid {
+                    write!(f, "\n  {}", v)?;
+                }
+                Ok(())
+            }
+            CliError::Quiz(e) => write!(f, "{}", e),
+            CliError::MissingValue(option) => write!(f, "`{}` needs a value", option),
+            CliError::InvalidValue(message) => write!(f, "{}", message),
+            CliError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<io::Error> for CliError {
+    fn from(e: io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
+
+impl From<quiz::QuizError> for CliError {
+    fn from(e: quiz::QuizError) -> Self {
+        CliError::Quiz(e)
+    }
+}
+
+/// Runs the example registered under `name`.
+pub fn run_named(name: &str, config: &RunConfig, out: &mut impl Write) -> Result<(), CliError> {
+    match find(name) {
+        Some(example) => Ok(example.run(out, config)?),
+        None => Err(unknown_example(name)),
+    }
+}
+
+fn unknown_example(name: &str) -> CliError {
+    CliError::UnknownExample {
+        name: name.to_string(),
+        valid: registry().iter().map(|e| e.name().to_string()).collect(),
+    }
+}
+
+/// Runs `examples` in order, each under a numbered banner, timing every `run()`.
+///
+/// Each example runs into a buffer that is copied to `out` afterwards, so writing to a slow
+/// terminal isn't counted as the example's time.
+pub fn run_examples(
+    examples: &[Box<dyn Example>],
+    config: &RunConfig,
+    out: &mut impl Write,
+) -> Result<RunReport, CliError> {
+    let mut report = RunReport::new();
+    for (i, example) in examples.iter().enumerate() {
+        let banner = format!(
+            "=== [{}/{}] {}: {} ===",
+            i + 1,
+            examples.len(),
+            title(example.as_ref()),
+            example.description()
+        );
+        writeln!(out, "{}", config.style.heading(&banner))?;
+        let mut buffer = Vec::new();
+        let start = Instant::now();
+        example.run(&mut buffer, config)?;
+        let duration = start.elapsed();
+        out.write_all(&buffer)?;
+        let lines = buffer.iter().filter(|&&b| b == b'\n').count();
+        report.record(example.name(), duration, lines);
+        writeln!(out)?;
+    }
+    Ok(report)
+}
+
+/// Runs every registered example, then prints the timings table and a summary.
+pub fn run_all(config: &RunConfig, out: &mut impl Write) -> Result<(), CliError> {
+    let examples = registry();
+    let start = Instant::now();
+    let report = run_examples(&examples, config, out)?;
+    write!(out, "{}", report::table(&report))?;
+    if let Some(slowest) = report.slowest() {
+        writeln!(
+            out,
+            "slowest: {} ({:.2?} of {:.2?})",
+            slowest.name,
+            slowest.duration,
+            report.total()
+        )?;
+    }
+    let footer = format!(
+        "=== ran {} examples in {:.2?} ===",
+        examples.len(),
+        start.elapsed()
+    );
+    writeln!(out, "{}", config.style.dim(&footer))?;
+    Ok(())
+}
+
+/// Prints every example with its rule and description, sorted by name.
+pub fn list(out: &mut impl Write) -> Result<(), CliError> {
+    let mut examples = registry();
+    examples.sort_by(|a, b| a.name().cmp(b.name()));
+    let width = examples.iter().map(|e| e.name().len()).max().unwrap_or(0);
+    for example in &examples {
+        writeln!(
+            out,
+            "{:width$}  rule {}  {}",
+            example.name(),
+            example.rule().number(),
+            example.description(),
+            width = width
+        )?;
+    }
+    Ok(())
+}
+
+/// Prints every exercise with its prompt, in the order they are meant to be done.
+pub fn list_exercises(out: &mut impl Write) -> Result<(), CliError> {
+    for (i, exercise) in EXERCISES.iter().enumerate() {
+        writeln!(out, "{}. {}", i + 1, exercise.name)?;
+        writeln!(out, "   {}", exercise.prompt)?;
+    }
+    writeln!(
+        out,
+        "fill them in in src/exercises/stubs.rs, then run `cargo test -- --ignored exercises`"
+    )?;
+    Ok(())
+}
+
+/// How results are printed, picked with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Removes `option` and the value after it from `args`, wherever they are, returning the value.
+fn take_option(args: &mut Vec<String>, option: &'static str) -> Result<Option<String>, CliError> {
+    match args.iter().position(|a| a == option) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => Err(CliError::MissingValue(option)),
+        None => Ok(None),
+    }
+}
+
+fn take_format(args: &mut Vec<String>) -> Result<Format, CliError> {
+    match take_option(args, "--format")?.as_deref() {
+        None | Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(other) => Err(CliError::InvalidValue(format!(
+            "invalid format `{}`, expected text or json",
+            other
+        ))),
+    }
+}
+
+/// `--list --format json`: every example's metadata, in registry order.
+pub fn list_json(out: &mut impl Write) -> Result<(), CliError> {
+    let infos: Vec<Versioned<ExampleInfo>> = registry()
+        .iter()
+        .map(|e| Versioned::new(ExampleInfo::of(e.as_ref())))
+        .collect();
+    Ok(json::write(out, &infos)?)
+}
+
+/// `--format json` for a run: one result for a named example, an array of them for all.
+fn run_json(name: Option<&str>, config: &RunConfig, out: &mut impl Write) -> Result<(), CliError> {
+    match name {
+        Some(name) => {
+            let example = find(name).ok_or_else(|| unknown_example(name))?;
+            let result = json::result_of(example.as_ref(), config)?;
+            Ok(json::write(out, &Versioned::new(result))?)
+        }
+        None => {
+            let results = registry()
+                .iter()
+                .map(|e| json::result_of(e.as_ref(), config).map(Versioned::new))
+                .collect::<io::Result<Vec<_>>>()?;
+            Ok(json::write(out, &results)?)
+        }
+    }
+}
+
+/// Removes `--color=<when>` from `args`, returning the choice, `auto` if there is none.
+fn take_color(args: &mut Vec<String>) -> Result<ColorChoice, CliError> {
+    match args.iter().position(|a| a.starts_with("--color=")) {
+        Some(i) => args.remove(i)["--color=".len()..]
+            .parse()
+            .map_err(CliError::InvalidValue),
+        None => Ok(ColorChoice::Auto),
+    }
+}
+
+/// Dispatches on the command line arguments, without the program name.
+pub fn run(args: &[String], out: &mut impl Write) -> Result<(), CliError> {
+    let mut args = args.to_vec();
+    let progress_file = take_option(&mut args, "--progress-file")?;
+    let format = take_format(&mut args)?;
+    let color = take_color(&mut args)?; // taken in either format, so it never reads as a name
+    let style = match format {
+        Format::Text => Style::detect(color),
+        Format::Json => Style::PLAIN, // escape codes have no place in JSON strings
+    };
+    let verbose = args.iter().any(|a| a == "-v" || a == "--verbose");
+    args.retain(|a| a != "-v" && a != "--verbose");
+    let config = RunConfig { verbose, style };
+    let progress_path = Path::new(progress_file.as_deref().unwrap_or(progress::DEFAULT_FILE));
+    match args.first().map(String::as_str) {
+        Some("--list") if format == Format::Json => {
+            no_extra_arguments(&args)?;
+            return list_json(out);
+        }
+        Some("--list") => {
+            no_extra_arguments(&args)?;
+            list(out)?;
+            let progress = Progress::load(progress_path)?;
+            let examples = registry();
+            writeln!(
+                out,
+                "you have completed {}/{} examples",
+                progress.completed_of(examples.iter().map(|e| e.name())),
+                examples.len()
+            )?;
+            return Ok(());
+        }
+        Some("--exercises" | "quiz" | "puzzles" | "export") if format == Format::Json => {
+            return Err(CliError::InvalidValue(format!(
+                "`{}` has no JSON output",
+                args[0]
+            )))
+        }
+        Some("--exercises") => {
+            no_extra_arguments(&args)?;
+            return list_exercises(out);
+        }
+        Some("export") => {
+            let dir = take_option(&mut args, "--out")?.unwrap_or_else(|| String::from("docs"));
+            no_extra_arguments(&args)?;
+            let summary = export::export(Path::new(&dir), &config)?;
+            for file in &summary.files {
+                writeln!(out, "wrote {}", file.display())?;
+            }
+            writeln!(out, "exported {} examples to {}", summary.files.len(), dir)?;
+            return Ok(());
+        }
+        Some("quiz") => {
+            let file = take_option(&mut args, "--file")?;
+            let seed = take_option(&mut args, "--seed")?
+                .map(|seed| {
+                    seed.parse::<u64>().map_err(|_| {
+                        CliError::InvalidValue(format!(
+                            "invalid seed `{}`, expected a number",
+                            seed
+                        ))
+                    })
+                })
+                .transpose()?;
+            let shuffle = args.iter().any(|a| a == "--shuffle");
+            args.retain(|a| a != "--shuffle");
+            no_extra_arguments(&args)?;
+            if seed.is_some() && !shuffle {
+                return Err(CliError::InvalidValue(String::from(
+                    "`--seed` only applies together with `--shuffle`",
+                )));
+            }
+            let mut questions = quiz::load_questions(file.as_deref().map(Path::new))?;
+            if shuffle {
+                let seed = seed.unwrap_or_else(XorShift::clock_seed);
+                writeln!(out, "shuffled with --seed {}", seed)?;
+                quiz::shuffle(&mut questions, &mut XorShift::new(seed));
+            }
+            let score = quiz::run(&questions, &mut io::stdin().lock(), out)?;
+            update_progress(progress_path, |p| p.record_quiz(score, questions.len()))?;
+        }
+        Some("puzzles") => {
+            no_extra_arguments(&args)?;
+            quiz::run(&puzzles::questions(), &mut io::stdin().lock(), out)?;
+        }
+        Some(name) => {
+            no_extra_arguments(&args)?;
+            match format {
+                Format::Text => run_named(name, &config, out)?,
+                Format::Json => run_json(Some(name), &config, out)?,
+            }
+            update_progress(progress_path, |p| p.mark_completed(name))?;
+        }
+        None => {
+            match format {
+                Format::Text => run_all(&config, out)?,
+                Format::Json => run_json(None, &config, out)?,
+            }
+            update_progress(progress_path, |p| {
+                for example in registry() {
+                    p.mark_completed(example.name());
+                }
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Fails if anything is left after the command, once its options have been taken out of `args`.
+fn no_extra_arguments(args: &[String]) -> Result<(), CliError> {
+    match args {
+        [command, extra, ..] => Err(CliError::InvalidValue(format!(
+            "unexpected argument `{}` after `{}`",
+            extra, command
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Loads the progress file, applies `update` and saves it again. Only commands that record
+/// something call this, so the others never touch the file.
+fn update_progress(path: &Path, update: impl FnOnce(&mut Progress)) -> Result<(), CliError> {
+    let mut progress = Progress::load(path)?;
+    update(&mut progress);
+    Ok(progress.save(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example::Rule;
+    use crate::raii::TempDir;
+    use std::thread;
+    use std::time::Duration;
+
+    /// `args` plus a `--progress-file` in `dir`, so tests don't touch the working directory.
+    fn with_progress(args: &[&str], dir: &TempDir) -> Vec<String> {
+        let path = dir.path().join("progress.json");
+        let mut args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        args.extend(["--progress-file".to_string(), path.display().to_string()]);
+        args
+    }
+
+    #[test]
+    fn run_named_runs_only_that_example() {
+        let mut out = Vec::new();
+        run_named("moves", &RunConfig::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("s1 was moved into s2"));
+        assert!(!text.contains("my_p"));
+    }
+
+    #[test]
+    fn unknown_name_lists_the_valid_names() {
+        let mut out = Vec::new();
+        let err = run_named("nope", &RunConfig::default(), &mut out).unwrap_err();
+        assert!(out.is_empty());
+        let message = err.to_string();
+        for example in registry() {
+            assert!(message.contains(example.name()));
+        }
+    }
+
+    #[test]
+    fn list_is_sorted_and_aligned() {
+        let mut out = Vec::new();
+        list(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let clone = text.lines().find(|l| l.starts_with("clone ")).unwrap();
+        assert!(clone.ends_with("  rule 2  deep copies of heap data"));
+        let columns: Vec<usize> = text.lines().map(|l| l.find("  rule ").unwrap()).collect();
+        assert!(columns.iter().all(|&c| c == columns[0]));
+        let names: Vec<&str> = text.lines().map(|l| l.split(' ').next().unwrap()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn list_contains_every_example_exactly_once() {
+        let dir = TempDir::new().unwrap();
+        let mut out = Vec::new();
+        run(&with_progress(&["--list"], &dir), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        for example in registry() {
+            let count = text
+                .lines()
+                .filter(|l| l.split(' ').next() == Some(example.name()))
+                .count();
+            assert_eq!(count, 1, "{}", example.name());
+        }
+    }
+
+    #[test]
+    fn run_all_prints_banners_in_registry_order() {
+        let mut out = Vec::new();
+        run_all(&RunConfig::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let banners: Vec<&str> = text.lines().filter(|l| l.starts_with("=== [")).collect();
+        let examples = registry();
+        assert_eq!(banners.len(), examples.len());
+        for (i, (banner, example)) in banners.iter().zip(&examples).enumerate() {
+            let expected = format!("=== [{}/{}] ", i + 1, examples.len());
+            assert!(banner.starts_with(&expected), "{}", banner);
+            assert!(banner.ends_with(&format!(": {} ===", example.description())));
+        }
+        assert!(text.contains(&format!(
+            "=== [3/{}] Clone: deep copies of heap data ===",
+            examples.len()
+        )));
+        assert!(text.contains(&format!("=== [7/{}] Mutable references: ", examples.len())));
+        let footer = text.lines().last().unwrap();
+        assert!(footer.starts_with(&format!("=== ran {} examples in ", examples.len())));
+    }
+
+    #[test]
+    fn no_arguments_runs_everything() {
+        let dir = TempDir::new().unwrap();
+        let mut all = Vec::new();
+        run(&with_progress(&[], &dir), &mut all).unwrap();
+        let mut moves = Vec::new();
+        run_named("moves", &RunConfig::default(), &mut moves).unwrap();
+        assert!(all.len() > moves.len());
+    }
+
+    #[test]
+    fn list_reports_completed_examples() {
+        let dir = TempDir::new().unwrap();
+        let total = registry().len();
+        let mut out = Vec::new();
+        run(&with_progress(&["--list"], &dir), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let expected = format!("you have completed 0/{} examples", total);
+        assert_eq!(text.lines().last().unwrap(), expected);
+
+        for name in ["moves", "clone", "moves"] {
+            run(&with_progress(&[name], &dir), &mut Vec::new()).unwrap();
+        }
+        let mut out = Vec::new();
+        run(&with_progress(&["--list"], &dir), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let expected = format!("you have completed 2/{} examples", total);
+        assert_eq!(text.lines().last().unwrap(), expected);
+    }
+
+    #[test]
+    fn progress_file_needs_a_path() {
+        let err = run(
+            &["moves".to_string(), "--progress-file".to_string()],
+            &mut Vec::new(),
+        );
+        assert!(matches!(
+            err,
+            Err(CliError::MissingValue("--progress-file"))
+        ));
+    }
+
+    #[test]
+    fn only_recording_commands_touch_the_progress_file() {
+        let dir = TempDir::new().unwrap();
+        let unreadable = dir.path().display().to_string(); // a directory, not a progress file
+        let args = |first: &[&str]| {
+            let mut args: Vec<String> = first.iter().map(|a| a.to_string()).collect();
+            args.extend(["--progress-file".to_string(), unreadable.clone()]);
+            args
+        };
+        assert!(run(&args(&["--list", "--format", "json"]), &mut Vec::new()).is_ok());
+        assert!(run(&args(&["--exercises"]), &mut Vec::new()).is_ok());
+        assert!(matches!(
+            run(&args(&["moves"]), &mut Vec::new()),
+            Err(CliError::Io(_))
+        ));
+
+        let fresh = TempDir::new().unwrap();
+        assert!(run(&with_progress(&["nope"], &fresh), &mut Vec::new()).is_err());
+        assert!(!fresh.path().join("progress.json").exists());
+    }
+
+    #[test]
+    fn color_always_styles_the_banners() {
+        let dir = TempDir::new().unwrap();
+        let mut out = Vec::new();
+        run(&with_progress(&["--color=always"], &dir), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("\x1b[1m=== [1/"));
+        let mut out = Vec::new();
+        run(&with_progress(&["--color=never"], &dir), &mut out).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains('\x1b'));
+    }
+
+    #[test]
+    fn invalid_color_is_an_error() {
+        let err = run(&["--color=sometimes".to_string()], &mut Vec::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid color `sometimes`, expected auto, always or never"
+        );
+    }
+
+    #[test]
+    fn verbose_flag_reaches_the_examples() {
+        let dir = TempDir::new().unwrap();
+        let mut quiet = Vec::new();
+        run(&with_progress(&["clone"], &dir), &mut quiet).unwrap();
+        let mut verbose = Vec::new();
+        run(&with_progress(&["-v", "clone"], &dir), &mut verbose).unwrap();
+        let (quiet, verbose) = (
+            String::from_utf8(quiet).unwrap(),
+            String::from_utf8(verbose).unwrap(),
+        );
+        assert!(!quiet.contains("  [my_s2] ptr "));
+        assert!(verbose.contains("  [my_s2] ptr "));
+        let mut long = Vec::new();
+        run(&with_progress(&["clone", "--verbose"], &dir), &mut long).unwrap();
+        assert_eq!(
+            String::from_utf8(long).unwrap().lines().count(),
+            verbose.lines().count()
+        );
+    }
+
+    #[test]
+    fn exercises_lists_every_prompt() {
+        let mut out = Vec::new();
+        list_exercises(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        for (i, exercise) in EXERCISES.iter().enumerate() {
+            assert!(text.contains(&format!("{}. {}\n", i + 1, exercise.name)));
+            assert!(text.contains(exercise.prompt));
+        }
+    }
+
+    fn output(args: &[&str], dir: &TempDir) -> String {
+        let mut out = Vec::new();
+        run(&with_progress(args, dir), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn list_json_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let text = output(&["--list", "--format", "json"], &dir);
+        let infos: Vec<Versioned<ExampleInfo>> = serde_json::from_str(&text).unwrap();
+        assert_eq!(infos.len(), registry().len());
+        assert!(infos
+            .iter()
+            .all(|i| i.schema_version == json::SCHEMA_VERSION));
+        assert_eq!(infos[0].data.name, "strings");
+        let back: Vec<Versioned<ExampleInfo>> =
+            serde_json::from_str(&serde_json::to_string(&infos).unwrap()).unwrap();
+        assert_eq!(back, infos);
+    }
+
+    #[test]
+    fn named_json_is_the_demo_result() {
+        use crate::demo_result::{Action, DemoResult};
+
+        let dir = TempDir::new().unwrap();
+        let text = output(&["moves", "--format", "json", "--color=always"], &dir);
+        let result: Versioned<DemoResult> = serde_json::from_str(&text).unwrap();
+        assert_eq!(result.schema_version, json::SCHEMA_VERSION);
+        assert_eq!(result.data.name, "moves");
+        assert_eq!(result.data.steps_of(Action::Move).count(), 1);
+        assert_eq!(result.data.output, output(&["moves"], &dir)); // and never colored
+        assert!(text.contains(r#""action": "move""#));
+    }
+
+    #[test]
+    fn text_stays_the_default_format() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(
+            output(&["functions"], &dir),
+            output(&["functions", "--format", "text"], &dir)
+        );
+        assert!(!output(&["--list"], &dir).starts_with('['));
+        let all: Vec<Versioned<serde_json::Value>> =
+            serde_json::from_str(&output(&["--format", "json"], &dir)).unwrap();
+        assert_eq!(all.len(), registry().len());
+    }
+
+    #[test]
+    fn invalid_formats_are_errors() {
+        let dir = TempDir::new().unwrap();
+        let err = run(&with_progress(&["--format", "xml"], &dir), &mut Vec::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid format `xml`, expected text or json"
+        );
+        let err = run(
+            &with_progress(&["quiz", "--format", "json"], &dir),
+            &mut Vec::new(),
+        );
+        assert!(matches!(err, Err(CliError::InvalidValue(_))));
+    }
+
+    struct Stub {
+        name: &'static str,
+        sleep: Duration,
+    }
+
+    impl Example for Stub {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn description(&self) -> &str {
+            "a stub"
+        }
+
+        fn rule(&self) -> Rule {
+            Rule::Owner
+        }
+
+        fn source(&self) -> &'static str {
+            ""
+        }
+
+        fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+            thread::sleep(self.sleep);
+            writeln!(out, "one\ntwo")
+        }
+    }
+
+    #[test]
+    fn run_examples_times_each_example() {
+        let examples: Vec<Box<dyn Example>> = vec![
+            Box::new(Stub {
+                name: "quick",
+                sleep: Duration::ZERO,
+            }),
+            Box::new(Stub {
+                name: "sleepy",
+                sleep: Duration::from_millis(5),
+            }),
+        ];
+        let mut out = Vec::new();
+        let report = run_examples(&examples, &RunConfig::default(), &mut out).unwrap();
+        let names: Vec<&str> = report.timings.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["quick", "sleepy"]);
+        assert_eq!(report.slowest().unwrap().name, "sleepy");
+        assert!(report.total() >= Duration::from_millis(5));
+        assert!(report.timings.iter().all(|t| t.lines == 2));
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("=== [2/2] Sleepy: a stub ===\none\ntwo\n"));
+    }
+
+    #[test]
+    fn run_all_ends_with_the_timings_table() {
+        let mut out = Vec::new();
+        run_all(&RunConfig::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let table = text
+            .lines()
+            .position(|l| l.starts_with("example "))
+            .unwrap();
+        let rows = &text.lines().collect::<Vec<_>>()[table + 1..];
+        assert_eq!(rows.len(), registry().len() + 2); // the slowest line and the footer
+        assert!(rows[0].starts_with("strings "));
+        assert!(rows[rows.len() - 2].starts_with("slowest: "));
+    }
+}
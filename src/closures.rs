@@ -0,0 +1,174 @@
+// Closures: Capturing the Environment --------------------------
+// A closure captures what it uses in the least demanding way it can: by shared reference (Fn), by
+// mutable reference (FnMut) or by value (FnOnce), and the ownership rules apply to those captures
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Calls a closure that only reads its captures, as many times as we like.
+pub fn call_twice<F: Fn() -> usize>(f: F) -> (usize, usize) {
+    (f(), f())
+}
+
+/// Calls a closure that mutates its captures `times` times.
+pub fn call_n<F: FnMut()>(mut f: F, times: usize) {
+    for _ in 0..times {
+        f();
+    }
+}
+
+/// Calls a closure that consumes its captures, which is only possible once.
+///
+/// An `FnOnce` closure can't be called a second time, the first call used up what it owned:
+///
+/// ```compile_fail
+/// let s = String::from("hello");
+/// let consume = move || s;
+/// let a = consume();
+/// let b = consume(); // error: use of moved value: `consume`
+/// ```
+pub fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+/// Fn: the closure borrows `s` immutably, so `s` is still usable afterwards.
+pub fn borrow_capture(s: &str) -> (usize, usize) {
+    call_twice(|| s.len())
+}
+
+/// FnMut: the closure borrows `log` mutably for as long as it is alive.
+pub fn mut_capture(log: &mut Vec<String>, times: usize) {
+    call_n(|| log.push(format!("call {}", log.len() + 1)), times);
+}
+
+/// FnOnce: the closure takes ownership of `s` and hands it back out when called.
+///
+/// Once the closure has been given `s`, the caller can no longer use it:
+///
+/// ```compile_fail
+/// let s = String::from("hello");
+/// let consume = move || s;
+/// println!("{}", s); // error: borrow of moved value: `s`
+/// consume();
+/// ```
+pub fn move_capture(s: String) -> String {
+    call_once(move || {
+        let mut s = s;
+        s.push_str(", consumed");
+        s
+    })
+}
+
+// Returning Closures -------------------------------------------
+// A closure returned from a function outlives that function's locals, so it has to own (move) what it
+// captures instead of borrowing it
+
+/// Returns a closure that owns `name` and can keep greeting after this function has returned.
+///
+/// Without `move` the closure would only borrow `name`, which is dropped when the factory returns:
+///
+/// ```compile_fail
+/// fn make_greeter(name: String) -> impl Fn() -> String {
+///     || format!("Hello, {}!", name) // error: closure may outlive the current function, but it borrows `name`
+/// }
+/// ```
+pub fn make_greeter(name: String) -> impl Fn() -> String {
+    move || format!("Hello, {}!", name)
+}
+
+/// Returns a boxed closure that owns its counter and bumps it on every call.
+pub fn make_counter() -> Box<dyn FnMut() -> u32> {
+    let mut count = 0;
+    Box::new(move || {
+        count += 1;
+        count
+    })
+}
+
+pub struct ClosuresExample;
+
+impl Example for ClosuresExample {
+    fn name(&self) -> &str {
+        "closures"
+    }
+
+    fn description(&self) -> &str {
+        "Fn, FnMut and FnOnce captures"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("closures.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let s = String::from("hello");
+        let (a, b) = borrow_capture(&s);
+        writeln!(out, "Fn:     len {} and {}, s = {} still usable", a, b, s)?;
+
+        let mut log = Vec::new();
+        mut_capture(&mut log, 3);
+        writeln!(out, "FnMut:  log = {:?}", log)?;
+
+        let consumed = move_capture(s); // s moves into the closure
+        writeln!(out, "FnOnce: {}", consumed)?;
+
+        let greet = make_greeter(String::from("Ferris"));
+        writeln!(out, "greeter: {}", greet())?;
+        let mut counter = make_counter();
+        let counts: Vec<u32> = (0..3).map(|_| counter()).collect();
+        writeln!(out, "counter: {:?}", counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_closure_leaves_capture_usable() {
+        let s = String::from("hello");
+        assert_eq!(borrow_capture(&s), (5, 5));
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn fn_mut_closure_changes_state_across_calls() {
+        let mut log = vec![String::from("start")];
+        mut_capture(&mut log, 3);
+        assert_eq!(log, ["start", "call 2", "call 3", "call 4"]);
+
+        let mut count = 0;
+        call_n(|| count += 2, 5);
+        assert_eq!(count, 10);
+    }
+
+    #[test]
+    fn fn_once_closure_consumes_capture() {
+        assert_eq!(move_capture(String::from("hi")), "hi, consumed");
+    }
+
+    #[test]
+    fn greeter_outlives_the_original_string() {
+        let greet = {
+            let name = String::from("Ferris");
+            make_greeter(name)
+        }; // the scope that created name has ended
+        assert_eq!(greet(), "Hello, Ferris!");
+        assert_eq!(greet(), "Hello, Ferris!");
+    }
+
+    #[test]
+    fn counter_owns_its_state() {
+        let mut counter = make_counter();
+        assert_eq!(counter(), 1);
+        assert_eq!(counter(), 2);
+        assert_eq!(counter(), 3);
+
+        let mut other = make_counter();
+        assert_eq!(other(), 1);
+    }
+}
@@ -0,0 +1,131 @@
+// Ownership in Conditionals ------------------------------------
+// Patterns in `if let`, `while let` and `match` bind by value unless told otherwise: matching an
+// owned Option moves the String out of it, matching `&opt` or binding with `ref` only borrows.
+// `while let Some(x) = v.pop()` is the by-value version on purpose, every item moves out in turn
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Pops every String off `v` and concatenates them, last pushed first. `v` ends up empty.
+///
+/// `if let Some(s) = opt` moves the String into `s`, so `opt` is unusable afterwards:
+///
+/// ```compile_fail
+/// let opt = Some(String::from("hello"));
+/// if let Some(s) = opt {
+///     drop(s);
+/// }
+/// println!("{:?}", opt); // error: borrow of partially moved value: `opt`
+/// ```
+pub fn drain_stack(v: &mut Vec<String>) -> String {
+    let mut joined = String::new();
+    while let Some(item) = v.pop() {
+        joined.push_str(&item);
+    } // each item is dropped at the end of its iteration, its text already copied over
+    joined
+}
+
+/// The String in `opt` if it is at least `min` bytes long, borrowed from `opt`.
+///
+/// The guard only borrows its bindings, so it can't move the String into something that takes it:
+///
+/// ```compile_fail
+/// fn is_long(s: String) -> bool {
+///     s.len() > 3
+/// }
+///
+/// let opt = Some(String::from("hello"));
+/// match opt {
+///     Some(s) if is_long(s) => println!("long"), // error: cannot move out of `s` in pattern guard
+///     _ => println!("short"),
+/// }
+/// ```
+pub fn first_long(opt: &Option<String>, min: usize) -> Option<&str> {
+    match opt {
+        Some(s) if s.len() >= min => Some(s), // `opt` is a reference, so `s` is a `&String`
+        _ => None,
+    }
+}
+
+pub struct ControlFlowExample;
+
+impl Example for ControlFlowExample {
+    fn name(&self) -> &str {
+        "control-flow"
+    }
+
+    fn description(&self) -> &str {
+        "if let, while let and match guards move or borrow"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("control_flow.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let opt = Some(String::from("hello"));
+        if let Some(s) = &opt {
+            writeln!(out, "if let Some(s) = &opt borrows: s = {}", s)?;
+        }
+        writeln!(out, "opt is still usable: {:?}", opt)?;
+        if let Some(s) = opt {
+            writeln!(out, "if let Some(s) = opt moves: s = {}, opt is gone", s)?;
+        }
+
+        let guarded = Some(String::from("borrowed"));
+        match guarded {
+            Some(ref s) if s.len() > 3 => writeln!(out, "Some(ref s) if s.len() > 3: {}", s)?,
+            _ => writeln!(out, "short or none")?,
+        }
+        writeln!(out, "with `ref` the match didn't move it: {:?}", guarded)?;
+        writeln!(
+            out,
+            "first_long(&guarded, 5) = {:?}, first_long(&guarded, 50) = {:?}",
+            first_long(&guarded, 5),
+            first_long(&guarded, 50)
+        )?;
+
+        let mut stack = vec![String::from("c"), String::from("b"), String::from("a")];
+        let joined = drain_stack(&mut stack);
+        writeln!(
+            out,
+            "while let pop() moved out {:?}, stack left {:?}",
+            joined, stack
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drain_stack_pops_in_reverse_and_empties() {
+        let mut v = vec![String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(drain_stack(&mut v), "cba");
+        assert!(v.is_empty());
+        assert_eq!(drain_stack(&mut v), "");
+    }
+
+    #[test]
+    fn first_long_borrows_when_long_enough() {
+        let opt = Some(String::from("hello"));
+        assert_eq!(first_long(&opt, 5), Some("hello"));
+        assert_eq!(first_long(&opt, 0), Some("hello"));
+        assert_eq!(first_long(&opt, 6), None);
+        assert_eq!(first_long(&None, 0), None);
+        // opt was only borrowed
+        assert_eq!(opt.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn first_long_points_into_the_option() {
+        let opt = Some(String::from("hello"));
+        let s = first_long(&opt, 1).unwrap();
+        assert_eq!(s.as_ptr(), opt.as_ref().unwrap().as_ptr());
+    }
+}
@@ -0,0 +1,265 @@
+// From and Into ------------------------------------------------
+// `From<T>` takes the T by value, so converting an owned value moves it into the new type, ideally
+// reusing its heap buffer. Converting from a borrow (`From<&str>`) has to allocate a copy instead,
+// and every type converts into itself for free, so `Into<Document>` accepts a Document as is
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Celsius(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fahrenheit(pub f64);
+
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Fahrenheit {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Celsius {
+        Celsius((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub body: String,
+}
+
+/// Moves the String in, its buffer becomes the document's body.
+impl From<String> for Document {
+    fn from(body: String) -> Document {
+        Document { body }
+    }
+}
+
+/// Only borrows the text, so it has to be copied into a new String.
+impl From<&str> for Document {
+    fn from(body: &str) -> Document {
+        Document {
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Accepts anything that converts into a Document.
+///
+/// Passing a String moves it, the caller can't use it afterwards:
+///
+/// ```compile_fail
+/// use ownership::conversions::store;
+///
+/// let text = String::from("draft");
+/// let doc = store(text);
+/// println!("{}", text); // error: borrow of moved value: `text`
+/// ```
+pub fn store<D: Into<Document>>(d: D) -> Document {
+    d.into()
+}
+
+/// An address with one `@` between a non-empty local part and domain.
+#[derive(Debug, PartialEq)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A failed conversion, carrying the String that was passed in so the caller gets it back.
+#[derive(Debug, PartialEq)]
+pub struct InvalidEmail {
+    pub original: String,
+    pub reason: String,
+}
+
+impl fmt::Display for InvalidEmail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is not an email address: {}",
+            self.original, self.reason
+        )
+    }
+}
+
+impl std::error::Error for InvalidEmail {}
+
+impl TryFrom<String> for EmailAddress {
+    type Error = InvalidEmail;
+
+    fn try_from(s: String) -> Result<EmailAddress, InvalidEmail> {
+        let reason = match s.split_once('@') {
+            _ if s.is_empty() => "it is empty",
+            None => "it has no @",
+            Some(("", _)) => "nothing before the @",
+            Some((_, "")) => "nothing after the @",
+            Some((_, domain)) if domain.contains('@') => "more than one @",
+            Some(_) if s.contains(char::is_whitespace) => "it contains whitespace",
+            Some(_) => return Ok(EmailAddress(s)),
+        };
+        Err(InvalidEmail {
+            original: s, // moved into the error, not cloned
+            reason: reason.to_string(),
+        })
+    }
+}
+
+/// Converts a batch, keeping the valid addresses and the rejected Strings apart.
+pub fn parse_all(inputs: Vec<String>) -> (Vec<EmailAddress>, Vec<InvalidEmail>) {
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    for input in inputs {
+        match EmailAddress::try_from(input) {
+            Ok(email) => valid.push(email),
+            Err(err) => invalid.push(err),
+        }
+    }
+    (valid, invalid)
+}
+
+/// Allocations made by `f` on this thread, when the `count-allocs` feature is on.
+#[cfg(feature = "count-allocs")]
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    let (result, stats) = crate::alloc_count::measure(f);
+    (result, Some(stats.allocations))
+}
+
+#[cfg(not(feature = "count-allocs"))]
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    (f(), None)
+}
+
+fn allocated(count: Option<usize>) -> String {
+    match count {
+        Some(n) => format!(", {} allocations", n),
+        None => String::new(),
+    }
+}
+
+pub struct ConversionsExample;
+
+impl Example for ConversionsExample {
+    fn name(&self) -> &str {
+        "conversions"
+    }
+
+    fn description(&self) -> &str {
+        "From and Into moving values into new types"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("conversions.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let boiling = Celsius(100.0);
+        let f: Fahrenheit = boiling.into(); // Celsius is Copy, boiling is still usable
+        writeln!(out, "{:?} = {:?}", boiling, f)?;
+
+        let text = String::from("moved in");
+        let (doc, allocs) = allocations(|| store(text));
+        writeln!(out, "store(String):   {:?}{}", doc, allocated(allocs))?;
+        let (doc, allocs) = allocations(|| store("copied in"));
+        writeln!(out, "store(&str):     {:?}{}", doc, allocated(allocs))?;
+        let (doc, allocs) = allocations(|| store(doc));
+        writeln!(out, "store(Document): {:?}{}", doc, allocated(allocs))?;
+        if allocs.is_none() {
+            writeln!(
+                out,
+                "(build with --features count-allocs to see which calls allocate)"
+            )?;
+        }
+
+        let inputs = ["ferris@rust-lang.org", "no-at-sign", "@example.com"].map(String::from);
+        let (valid, invalid) = parse_all(inputs.into());
+        writeln!(out, "parse_all: {} valid {:?}", valid.len(), valid)?;
+        for err in invalid {
+            writeln!(out, "  {} (the String came back)", err)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::strings;
+
+    #[test]
+    fn temperatures_round_trip() {
+        assert_eq!(Fahrenheit::from(Celsius(100.0)), Fahrenheit(212.0));
+        assert_eq!(Celsius::from(Fahrenheit(32.0)), Celsius(0.0));
+        let c: Celsius = Fahrenheit::from(Celsius(37.0)).into();
+        assert!((c.0 - 37.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn store_a_string_moves_its_buffer() {
+        let text = String::from("body");
+        let ptr = text.as_ptr();
+        let doc = store(text);
+        assert_eq!(doc.body, "body");
+        assert_eq!(doc.body.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn store_a_str_copies_it() {
+        let text = "body";
+        let doc = store(text);
+        assert_eq!(doc.body, text);
+        assert_ne!(doc.body.as_ptr(), text.as_ptr());
+    }
+
+    #[test]
+    fn store_a_document_passes_it_through() {
+        let doc = Document::from("body");
+        let ptr = doc.body.as_ptr();
+        let stored = store(doc);
+        assert_eq!(stored.body.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn valid_addresses_convert() {
+        let email = EmailAddress::try_from(String::from("a@b.c")).unwrap();
+        assert_eq!(email.as_str(), "a@b.c");
+    }
+
+    #[test]
+    fn invalid_addresses_give_the_string_back() {
+        for (input, reason) in [
+            ("", "it is empty"),
+            ("no-at-sign", "it has no @"),
+            ("@b.c", "nothing before the @"),
+            ("a@", "nothing after the @"),
+            ("a@b@c", "more than one @"),
+            ("a b@c", "it contains whitespace"),
+        ] {
+            let s = String::from(input);
+            let ptr = s.as_ptr();
+            let err = EmailAddress::try_from(s).unwrap_err();
+            assert_eq!(err.original, input);
+            assert_eq!(err.original.as_ptr(), ptr); // the very same allocation
+            assert_eq!(err.reason, reason);
+        }
+    }
+
+    #[test]
+    fn parse_all_partitions_in_order() {
+        let (valid, invalid) = parse_all(strings(&["a@b", "bad", "c@d", ""]));
+        let valid: Vec<&str> = valid.iter().map(EmailAddress::as_str).collect();
+        assert_eq!(valid, ["a@b", "c@d"]);
+        let originals: Vec<&str> = invalid.iter().map(|e| e.original.as_str()).collect();
+        assert_eq!(originals, ["bad", ""]);
+        assert_eq!(parse_all(Vec::new()), (Vec::new(), Vec::new()));
+    }
+}
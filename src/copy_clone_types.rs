@@ -0,0 +1,103 @@
+// Copy and Clone on Custom Types -----------------------------------
+// A struct can derive Copy only if every field is Copy. Point (two i32s) can, so passing it
+// around copies it like an integer. Label owns a String, so it can only be Clone: it is moved
+// unless you explicitly ask for a deep copy. See tests/compile_fail/derive_copy_with_string.rs
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub text: String,
+}
+
+/// Like `makes_copy`: the caller's Point is copied in and stays usable.
+pub fn makes_copy_point(p: Point) -> i32 {
+    p.x + p.y
+} // the copy goes out of scope, nothing special happens
+
+/// Like `takes_ownership`: the Label is moved in and dropped at the end.
+pub fn takes_ownership_label(label: Label) -> usize {
+    label.text.len()
+} // label goes out of scope and its String is freed
+
+pub struct CopyCloneTypesExample;
+
+impl Example for CopyCloneTypesExample {
+    fn name(&self) -> &str {
+        "copy-clone-types"
+    }
+
+    fn description(&self) -> &str {
+        "deriving Copy and Clone on your own types"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("copy_clone_types.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let p = Point { x: 1, y: 2 };
+        let sum = makes_copy_point(p);
+        writeln!(
+            out,
+            "makes_copy_point(p) = {}, p still usable: {:?}",
+            sum, p
+        )?;
+
+        let label = Label {
+            text: String::from("hello"),
+        };
+        let len = takes_ownership_label(label.clone()); // give away a deep copy
+        writeln!(
+            out,
+            "takes_ownership_label(label.clone()) = {}, label kept: {:?}",
+            len, label
+        )?;
+        let len = takes_ownership_label(label); // give away the original
+        writeln!(out, "takes_ownership_label(label) = {}, label is gone", len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passing_a_point_leaves_the_original_usable() {
+        let p = Point { x: 3, y: 4 };
+        assert_eq!(makes_copy_point(p), 7);
+        assert_eq!(makes_copy_point(p), 7); // p was copied, not moved
+        assert_eq!(p, Point { x: 3, y: 4 });
+    }
+
+    #[test]
+    fn passing_a_label_needs_a_clone_to_keep_it() {
+        let label = Label {
+            text: String::from("keep"),
+        };
+        assert_eq!(takes_ownership_label(label.clone()), 4);
+        assert_eq!(label.text, "keep");
+        assert_eq!(takes_ownership_label(label), 4); // the last use gives it up
+    }
+
+    #[test]
+    fn a_cloned_label_owns_its_own_buffer() {
+        let label = Label {
+            text: String::from("deep"),
+        };
+        let copy = label.clone();
+        assert_eq!(copy, label);
+        assert_ne!(copy.text.as_ptr(), label.text.as_ptr());
+    }
+}
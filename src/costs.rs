@@ -0,0 +1,75 @@
+// The Cost of Clone, Borrow and Move ---------------------------
+// Pairs of functions that compute the same thing, one paying for a deep copy and one borrowing or
+// moving instead. The criterion benches in benches/ownership.rs time them against each other
+use std::rc::Rc;
+
+/// Length of a String the caller had to clone to hand over.
+pub fn length_by_clone(s: String) -> usize {
+    s.len()
+}
+
+/// Length of a borrowed string, no copy needed.
+pub fn length_by_ref(s: &str) -> usize {
+    s.len()
+}
+
+/// Builds a new Vec by cloning every String in `source`, which stays untouched.
+pub fn collect_by_clone(source: &[String]) -> Vec<String> {
+    source.to_vec()
+}
+
+/// Builds a new Vec by moving every String out of `source`, which is left empty.
+#[allow(clippy::drain_collect)] // mem::take would skip the per-element moves being measured
+pub fn collect_by_drain(source: &mut Vec<String>) -> Vec<String> {
+    source.drain(..).collect()
+}
+
+/// A second owner of the same text through `Rc`: a reference count bump.
+pub fn share_rc(s: &Rc<String>) -> Rc<String> {
+    Rc::clone(s)
+}
+
+/// A second owner of the same text by cloning it: a new heap buffer.
+#[allow(clippy::ptr_arg)] // the String is what gets cloned
+pub fn share_string(s: &String) -> String {
+    s.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_lengths_agree() {
+        let s = "x".repeat(1024);
+        assert_eq!(length_by_clone(s.clone()), 1024);
+        assert_eq!(length_by_ref(&s), 1024);
+    }
+
+    #[test]
+    fn clone_keeps_the_source_and_drain_empties_it() {
+        let mut source: Vec<String> = ["a", "b", "c"].map(String::from).into();
+        let cloned = collect_by_clone(&source);
+        assert_eq!(cloned, source);
+        assert_ne!(cloned[0].as_ptr(), source[0].as_ptr());
+
+        let ptr = source[0].as_ptr();
+        let drained = collect_by_drain(&mut source);
+        assert_eq!(drained, cloned);
+        assert_eq!(drained[0].as_ptr(), ptr);
+        assert!(source.is_empty());
+    }
+
+    #[test]
+    fn rc_shares_and_clone_copies() {
+        let rc = Rc::new(String::from("shared"));
+        let other = share_rc(&rc);
+        assert!(Rc::ptr_eq(&rc, &other));
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        let s = String::from("copied");
+        let copy = share_string(&s);
+        assert_eq!(copy, s);
+        assert_ne!(copy.as_ptr(), s.as_ptr());
+    }
+}
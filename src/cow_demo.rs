@@ -0,0 +1,152 @@
+// Clone on Write: Cow<str> ------------------------------------------
+// Cow (clone on write) is either a borrow or an owned value. A function that usually leaves its
+// input alone can return the borrow, and only pay for a heap allocation when it has to change it
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Lowercases `input`, allocating only if it contains uppercase characters.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    if input.chars().any(char::is_uppercase) {
+        Cow::Owned(input.to_lowercase())
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Escapes `&`, `<`, `>`, `"` and `'` for HTML, allocating only if one of them is present.
+pub fn escape_html(input: &str) -> Cow<'_, str> {
+    let needs_escape = |c: char| matches!(c, '&' | '<' | '>' | '"' | '\'');
+    if !input.contains(needs_escape) {
+        return Cow::Borrowed(input);
+    }
+    let mut escaped = String::with_capacity(input.len() + 8);
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+#[allow(clippy::ptr_arg)] // needs the Cow itself to tell the variants apart
+fn allocation(cow: &Cow<'_, str>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "borrowed, no allocation",
+        Cow::Owned(_) => "owned, allocated on the heap",
+    }
+}
+
+pub struct CowExample;
+
+impl Example for CowExample {
+    fn name(&self) -> &str {
+        "cow"
+    }
+
+    fn description(&self) -> &str {
+        "Cow only allocates when the data has to change"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("cow_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        for input in ["hello", "Hello", "already lowercase", "SHOUT"] {
+            let result = normalize(input);
+            writeln!(
+                out,
+                "normalize({:?}) = {:?}: {}",
+                input,
+                result,
+                allocation(&result)
+            )?;
+        }
+        for input in ["plain text", "<b>bold</b> & co"] {
+            let result = escape_html(input);
+            writeln!(
+                out,
+                "escape_html({:?}) = {:?}: {}",
+                input,
+                result,
+                allocation(&result)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercase_input_is_borrowed() {
+        let input = String::from("hello");
+        let result = normalize(&input);
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(result.as_ptr(), input.as_ptr()); // no copy was made
+    }
+
+    #[test]
+    fn uppercase_input_is_owned() {
+        let result = normalize("HeLLo");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "hello");
+    }
+
+    #[test]
+    fn non_ascii_uppercase_is_lowered() {
+        assert_eq!(normalize("ÉCOLE"), "école");
+        assert!(matches!(normalize("école"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn plain_text_is_not_escaped() {
+        let input = "nothing to see";
+        let result = escape_html(input);
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(result.as_ptr(), input.as_ptr());
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        let result = escape_html(r#"<a href="x">Tom & Jerry's</a>"#);
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(
+            result,
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn into_owned_reuses_the_owned_buffer() {
+        let cow = normalize("ABC");
+        let ptr = cow.as_ptr();
+        assert!(matches!(cow, Cow::Owned(_)));
+        let owned: String = cow.into_owned(); // moves the String out of the Cow
+        assert_eq!(owned, "abc");
+        assert_eq!(owned.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn into_owned_copies_the_borrowed_case() {
+        let input = "abc";
+        let cow = normalize(input);
+        assert!(matches!(cow, Cow::Borrowed(_)));
+        let owned: String = cow.into_owned(); // the borrowed &str is copied into a new String
+        assert_eq!(owned, input);
+        assert_ne!(owned.as_ptr(), input.as_ptr());
+    }
+}
@@ -0,0 +1,174 @@
+// Reference Cycles ---------------------------------------------
+// Rc frees a value when its strong count reaches zero. Two values pointing at each other with Rc
+// keep each other's count above zero forever: the memory leaks, even though nothing can reach it.
+// A Weak reference doesn't count as an owner, so using it for the back edge breaks the cycle
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::{Rc, Weak};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, DropLog, Tracer};
+
+/// List node whose `next` edge is a strong `Rc`, so two nodes can own each other.
+pub struct Node {
+    pub value: i32,
+    pub next: RefCell<Option<Rc<Node>>>,
+    _tracer: Tracer,
+}
+
+impl Node {
+    pub fn new(value: i32, log: &DropLog) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            next: RefCell::new(None),
+            _tracer: Tracer::new(&format!("node {}", value), log),
+        })
+    }
+}
+
+/// Node with a strong forward edge and a `Weak` back edge.
+pub struct WeakNode {
+    pub value: i32,
+    pub next: RefCell<Option<Rc<WeakNode>>>,
+    pub prev: RefCell<Weak<WeakNode>>,
+    _tracer: Tracer,
+}
+
+impl WeakNode {
+    pub fn new(value: i32, log: &DropLog) -> Rc<WeakNode> {
+        Rc::new(WeakNode {
+            value,
+            next: RefCell::new(None),
+            prev: RefCell::new(Weak::new()),
+            _tracer: Tracer::new(&format!("node {}", value), log),
+        })
+    }
+}
+
+fn counts<T>(name: &str, rc: &Weak<T>) -> String {
+    format!(
+        "{}: strong = {}, weak = {}",
+        name,
+        rc.strong_count(),
+        rc.weak_count()
+    )
+}
+
+/// Wires `a -> b -> a` with strong edges, then drops both handles: neither node is freed.
+pub fn rc_cycle(log: &DropLog, out: &mut dyn Write) -> io::Result<()> {
+    let a = Node::new(1, log);
+    let b = Node::new(2, log);
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    *b.next.borrow_mut() = Some(Rc::clone(&a)); // the cycle
+
+    // Weak handles let us keep looking at the counts after the strong handles are gone
+    let (watch_a, watch_b) = (Rc::downgrade(&a), Rc::downgrade(&b));
+    writeln!(out, "before dropping the head")?;
+    writeln!(out, "  {}", counts("a", &watch_a))?;
+    writeln!(out, "  {}", counts("b", &watch_b))?;
+
+    drop(a);
+    drop(b);
+    writeln!(out, "after dropping the head")?;
+    writeln!(out, "  {}", counts("a", &watch_a))?;
+    writeln!(out, "  {}", counts("b", &watch_b))?;
+    writeln!(out, "  leaked: {}", watch_a.upgrade().is_some())
+}
+
+/// Wires `a -> b` strongly and `b -> a` weakly, then drops both handles: both nodes are freed.
+pub fn weak_back_edge(log: &DropLog, out: &mut dyn Write) -> io::Result<()> {
+    let a = WeakNode::new(1, log);
+    let b = WeakNode::new(2, log);
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    *b.prev.borrow_mut() = Rc::downgrade(&a); // back edge doesn't own a
+    let (watch_a, watch_b) = (Rc::downgrade(&a), Rc::downgrade(&b));
+    writeln!(out, "before dropping the head")?;
+    writeln!(out, "  {}", counts("a", &watch_a))?;
+    writeln!(out, "  {}", counts("b", &watch_b))?;
+    let prev = b.prev.borrow().upgrade().map(|n| n.value);
+    writeln!(out, "  b.prev = {:?}", prev)?;
+
+    drop(a);
+    drop(b);
+    writeln!(out, "after dropping the head")?;
+    writeln!(out, "  {}", counts("a", &watch_a))?;
+    writeln!(out, "  {}", counts("b", &watch_b))?;
+    writeln!(out, "  leaked: {}", watch_a.upgrade().is_some())
+}
+
+pub struct CyclesExample;
+
+impl Example for CyclesExample {
+    fn name(&self) -> &str {
+        "cycles"
+    }
+
+    fn description(&self) -> &str {
+        "reference cycles leak, Weak back edges don't"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("cycles.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let log = new_log();
+        writeln!(out, "Rc cycle:")?;
+        rc_cycle(&log, out)?;
+        writeln!(out, "  drops logged: {}", drops(&log))?;
+
+        let log = new_log();
+        writeln!(out, "Weak back edge:")?;
+        weak_back_edge(&log, out)?;
+        writeln!(out, "  drops logged: {}", drops(&log))
+    }
+}
+
+fn drops(log: &DropLog) -> usize {
+    log.borrow()
+        .iter()
+        .filter(|e| e.starts_with("drop"))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_cycle_never_frees_its_nodes() {
+        let log = new_log();
+        rc_cycle(&log, &mut Vec::new()).unwrap();
+        assert_eq!(drops(&log), 0);
+    }
+
+    #[test]
+    fn weak_back_edge_frees_both_nodes() {
+        let log = new_log();
+        weak_back_edge(&log, &mut Vec::new()).unwrap();
+        assert_eq!(drops(&log), 2);
+    }
+
+    #[test]
+    fn counts_show_the_leak() {
+        let mut out = Vec::new();
+        rc_cycle(&new_log(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("after dropping the head\n  a: strong = 1, weak = 1\n"));
+        assert!(text.ends_with("leaked: true\n"));
+    }
+
+    #[test]
+    fn counts_show_the_cleanup() {
+        let mut out = Vec::new();
+        weak_back_edge(&new_log(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("  a: strong = 1, weak = 2\n")); // b.prev and our watch
+        assert!(text.contains("after dropping the head\n  a: strong = 0, weak = 0\n"));
+        assert!(text.ends_with("leaked: false\n"));
+    }
+}
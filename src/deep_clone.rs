@@ -0,0 +1,121 @@
+// Clone Is Not Free ------------------------------------------------
+// A hand-written Clone that counts every deep copy, so the cost of "just clone it" shows up as a
+// number instead of a vague feeling
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Shared, injectable count of deep copies.
+pub type CloneCounter = Rc<Cell<usize>>;
+
+pub struct DeepBuffer {
+    pub bytes: Vec<u8>,
+    pub name: String,
+    clones: CloneCounter,
+}
+
+impl DeepBuffer {
+    pub fn new(name: &str, bytes: &[u8], clones: &CloneCounter) -> DeepBuffer {
+        DeepBuffer {
+            bytes: bytes.to_vec(),
+            name: name.to_string(),
+            clones: Rc::clone(clones),
+        }
+    }
+}
+
+impl Clone for DeepBuffer {
+    fn clone(&self) -> DeepBuffer {
+        self.clones.set(self.clones.get() + 1);
+        DeepBuffer {
+            bytes: self.bytes.clone(),       // new heap allocation
+            name: self.name.clone(),         // and another one
+            clones: Rc::clone(&self.clones), // just a count bump, not a deep copy
+        }
+    }
+}
+
+/// Total size of every buffer, written the clone-happy way: one deep copy per element.
+#[allow(clippy::redundant_clone)] // the clones are the point of the demo
+pub fn total_size_cloning(buffers: &[DeepBuffer]) -> usize {
+    let owned: Vec<DeepBuffer> = buffers.to_vec();
+    owned.iter().map(|b| b.bytes.len() + b.name.len()).sum()
+}
+
+/// Same result, reading through references: no copies at all.
+pub fn total_size_borrowing(buffers: &[DeepBuffer]) -> usize {
+    buffers.iter().map(|b| b.bytes.len() + b.name.len()).sum()
+}
+
+pub struct DeepCloneExample;
+
+impl Example for DeepCloneExample {
+    fn name(&self) -> &str {
+        "deep-clone"
+    }
+
+    fn description(&self) -> &str {
+        "counting the deep copies clone makes"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("deep_clone.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let clones = CloneCounter::default();
+        let buffers = vec![
+            DeepBuffer::new("a", b"hello", &clones),
+            DeepBuffer::new("b", b"world", &clones),
+            DeepBuffer::new("c", b"!", &clones),
+        ];
+
+        let size = total_size_cloning(&buffers);
+        writeln!(out, "cloning:   size {}, {} clones", size, clones.take())?;
+        let size = total_size_borrowing(&buffers);
+        writeln!(out, "borrowing: size {}, {} clones", size, clones.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffers(clones: &CloneCounter) -> Vec<DeepBuffer> {
+        (0..4)
+            .map(|i| DeepBuffer::new(&format!("buf{}", i), &[0; 10], clones))
+            .collect()
+    }
+
+    #[test]
+    fn borrowing_makes_no_clones() {
+        let clones = CloneCounter::default();
+        let buffers = buffers(&clones);
+        assert_eq!(total_size_borrowing(&buffers), 4 * 14);
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[test]
+    fn cloning_makes_one_clone_per_buffer() {
+        let clones = CloneCounter::default();
+        let buffers = buffers(&clones);
+        assert_eq!(total_size_cloning(&buffers), 4 * 14);
+        assert_eq!(clones.get(), 4);
+    }
+
+    #[test]
+    fn clone_is_a_deep_copy() {
+        let clones = CloneCounter::default();
+        let original = DeepBuffer::new("x", b"abc", &clones);
+        let copy = original.clone();
+        assert_eq!(clones.get(), 1);
+        assert_eq!(copy.bytes, original.bytes);
+        assert_ne!(copy.bytes.as_ptr(), original.bytes.as_ptr());
+    }
+}
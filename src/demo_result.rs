@@ -0,0 +1,109 @@
+// DemoResult: what a demo did, as data as well as text
+// The core demos record every create, copy, move, clone, borrow and drop as a Step, next to the
+// text they print, so other tools can use the crate without parsing the walkthrough
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Create,
+    /// A bitwise copy, the source stays usable.
+    Copy,
+    /// Ownership moves, the source can't be used any more.
+    Move,
+    /// A deep copy, both stay usable and own separate data.
+    Clone,
+    /// A reference is taken, the owner doesn't change.
+    Borrow,
+    Drop,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Step {
+    pub action: Action,
+    /// The variables involved, the source first for copies, moves, clones and borrows.
+    pub vars: Vec<String>,
+}
+
+impl Step {
+    pub fn new(action: Action, vars: &[&str]) -> Step {
+        Step {
+            action,
+            vars: vars.iter().map(|v| v.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DemoResult {
+    /// The name of the example that produced it.
+    pub name: String,
+    pub steps: Vec<Step>,
+    /// Everything the demo printed, exactly as `run` shows it.
+    pub output: String,
+}
+
+impl DemoResult {
+    pub fn new(name: &str) -> DemoResult {
+        DemoResult {
+            name: name.to_string(),
+            steps: Vec::new(),
+            output: String::new(),
+        }
+    }
+
+    pub fn step(&mut self, action: Action, vars: &[&str]) {
+        self.steps.push(Step::new(action, vars));
+    }
+
+    /// The steps that performed `action`, in order.
+    pub fn steps_of(&self, action: Action) -> impl Iterator<Item = &Step> {
+        self.steps.iter().filter(move |step| step.action == action)
+    }
+
+    /// Writes the text of the demo, the same a plain `run` would have written.
+    pub fn render(&self, out: &mut dyn Write) -> io::Result<()> {
+        out.write_all(self.output.as_bytes())
+    }
+}
+
+/// Collects the demo's text, so demos can keep writing with `writeln!`. Never fails.
+impl Write for DemoResult {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.push_str(&String::from_utf8_lossy(buf));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_end_up_in_the_output() {
+        let mut result = DemoResult::new("test");
+        writeln!(result, "x = {}", 5).unwrap();
+        write!(result, "done").unwrap();
+        assert_eq!(result.output, "x = 5\ndone");
+        let mut rendered = Vec::new();
+        result.render(&mut rendered).unwrap();
+        assert_eq!(rendered, b"x = 5\ndone");
+    }
+
+    #[test]
+    fn steps_of_filters_by_action() {
+        let mut result = DemoResult::new("test");
+        result.step(Action::Create, &["s1"]);
+        result.step(Action::Move, &["s1", "s2"]);
+        result.step(Action::Drop, &["s2"]);
+        let moves: Vec<&Step> = result.steps_of(Action::Move).collect();
+        assert_eq!(moves, [&Step::new(Action::Move, &["s1", "s2"])]);
+        assert_eq!(result.steps_of(Action::Borrow).count(), 0);
+    }
+}
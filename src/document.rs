@@ -0,0 +1,135 @@
+// Methods Returning Borrows ------------------------------------
+// `fn title(&self) -> &str` elides to `fn title<'a>(&'a self) -> &'a str`: the slice borrows the
+// whole Document, not just its body, so nothing can take `&mut self` while the slice is in use.
+// The method version of `first_word`, and not the same type as `conversions::Document`
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+pub struct Document {
+    body: String,
+}
+
+impl Document {
+    pub fn new(body: String) -> Document {
+        Document { body }
+    }
+
+    /// The first line of the body, all of it if there is no newline.
+    ///
+    /// The title borrows the Document, so the body can't be replaced while it is held:
+    ///
+    /// ```compile_fail
+    /// use ownership::document::Document;
+    ///
+    /// let mut doc = Document::new(String::from("Old title\n\ntext"));
+    /// let title = doc.title();
+    /// doc.set_body(String::from("New title")); // error: cannot borrow `doc` as mutable because it is also borrowed as immutable
+    /// println!("{}", title);
+    /// ```
+    pub fn title(&self) -> &str {
+        self.body.lines().next().unwrap_or("")
+    }
+
+    /// Paragraph `n`, counting from zero, paragraphs being separated by blank lines.
+    pub fn paragraph(&self, n: usize) -> Option<&str> {
+        self.body
+            .split("\n\n")
+            .map(|p| p.trim_matches('\n'))
+            .filter(|p| !p.is_empty())
+            .nth(n)
+    }
+
+    /// Replaces the body, dropping the old one. Any title or paragraph borrowed before must be done.
+    pub fn set_body(&mut self, body: String) {
+        self.body = body;
+    }
+}
+
+pub struct DocumentExample;
+
+impl Example for DocumentExample {
+    fn name(&self) -> &str {
+        "document"
+    }
+
+    fn description(&self) -> &str {
+        "a slice returned from a &self method borrows the whole value"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("document.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut doc = Document::new(String::from(
+            "Ownership\n\nEach value has an owner.\n\nThere is only one owner at a time.",
+        ));
+        let title = doc.title();
+        writeln!(out, "title = {:?}", title)?;
+        writeln!(out, "paragraph(1) = {:?}", doc.paragraph(1))?;
+        writeln!(out, "paragraph(5) = {:?}", doc.paragraph(5))?;
+        // title isn't used after this point, so the borrow of doc has ended
+        doc.set_body(String::from("Borrowing"));
+        writeln!(out, "after set_body, title = {:?}", doc.title())?;
+        let owned = doc.title().to_string();
+        doc.set_body(String::from("Slices"));
+        writeln!(
+            out,
+            "an owned copy outlives set_body: {:?}, now {:?}",
+            owned,
+            doc.title()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(body: &str) -> Document {
+        Document::new(body.to_string())
+    }
+
+    #[test]
+    fn title_without_a_newline_is_the_whole_body() {
+        assert_eq!(doc("just a title").title(), "just a title");
+        assert_eq!(doc("").title(), "");
+        assert_eq!(doc("just a title").paragraph(0), Some("just a title"));
+    }
+
+    #[test]
+    fn paragraphs_are_separated_by_blank_lines() {
+        let d = doc("Title\nsubtitle\n\nfirst\nstill first\n\n\nsecond\n");
+        assert_eq!(d.title(), "Title");
+        assert_eq!(d.paragraph(0), Some("Title\nsubtitle"));
+        assert_eq!(d.paragraph(1), Some("first\nstill first"));
+        assert_eq!(d.paragraph(2), Some("second"));
+    }
+
+    #[test]
+    fn out_of_range_paragraphs_are_none() {
+        let d = doc("a\n\nb");
+        assert_eq!(d.paragraph(2), None);
+        assert_eq!(d.paragraph(usize::MAX), None);
+        assert_eq!(doc("").paragraph(0), None);
+    }
+
+    #[test]
+    fn set_body_replaces_what_the_methods_see() {
+        let mut d = doc("old\n\ntext");
+        d.set_body(String::from("new"));
+        assert_eq!(d.title(), "new");
+        assert_eq!(d.paragraph(1), None);
+    }
+
+    #[test]
+    fn title_borrows_from_the_body() {
+        let d = doc("Title\nrest");
+        assert!(std::ptr::eq(d.title().as_ptr(), d.body.as_ptr()));
+    }
+}
@@ -0,0 +1,178 @@
+// Drop Order ---------------------------------------------------
+// Rule 3 made visible: every Tracer logs the moment it is dropped
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{mark, new_log, DropLog, Tracer};
+
+pub mod nested;
+
+/// Values in a block are dropped in reverse order of declaration when the block ends.
+pub fn block_order(log: &DropLog) {
+    {
+        let _a = Tracer::new("a", log);
+        let _b = Tracer::new("b", log);
+        let _c = Tracer::new("c", log);
+        mark(log, "end of block");
+    } // c, b, a
+}
+
+pub struct Pair {
+    pub first: Tracer,
+    pub second: Tracer,
+}
+
+/// Struct fields are dropped in declaration order, after the struct itself.
+pub fn field_order(log: &DropLog) {
+    let _pair = Pair {
+        second: Tracer::new("second", log), // initialization order doesn't matter
+        first: Tracer::new("first", log),
+    };
+    mark(log, "end of scope");
+} // first, then second
+
+/// `std::mem::drop` takes ownership of the value, so it's dropped right away.
+pub fn early_drop(log: &DropLog) {
+    let a = Tracer::new("a", log);
+    let _b = Tracer::new("b", log);
+    drop(a);
+    mark(log, "end of scope");
+} // only b is left to drop here
+
+/// Shadowing hides the old binding but does not drop it: it still lives until the end of the scope.
+pub fn shadowing(log: &DropLog) {
+    let t = Tracer::new("first", log);
+    mark(log, &format!("shadowing {}", t.label()));
+    let t = Tracer::new("second", log);
+    mark(log, &format!("end of scope, {} is visible", t.label()));
+} // second is dropped, then the shadowed first
+
+/// Assigning to a `mut` binding drops the old value immediately.
+pub fn reassignment(log: &DropLog) {
+    let mut t = Tracer::new("first", log);
+    mark(log, &format!("reassigning {}", t.label()));
+    t = Tracer::new("second", log); // first is dropped here
+    mark(log, &format!("end of scope, {} is visible", t.label()));
+}
+
+/// Hands [`nested::run`] its own `Rc` handle to the log, the other demos only borrow it.
+fn nested_blocks(log: &DropLog) {
+    nested::run(Rc::clone(log));
+}
+
+type Demo = fn(&DropLog);
+
+pub struct DropOrderExample;
+
+impl Example for DropOrderExample {
+    fn name(&self) -> &str {
+        "drop-order"
+    }
+
+    fn description(&self) -> &str {
+        "when and in which order values are dropped"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mod.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let demos: [(&str, Demo); 6] = [
+            ("block", block_order),
+            ("struct fields", field_order),
+            ("mem::drop", early_drop),
+            ("shadowing", shadowing),
+            ("reassignment", reassignment),
+            ("nested blocks", nested_blocks),
+        ];
+        for (title, demo) in demos {
+            let log = new_log();
+            demo(&log);
+            writeln!(out, "{}:", title)?;
+            for event in log.borrow().iter() {
+                writeln!(out, "  {}", event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracer::events;
+
+    #[test]
+    fn block_drops_in_reverse_declaration_order() {
+        assert_eq!(
+            events(block_order),
+            [
+                "new: a",
+                "new: b",
+                "new: c",
+                "end of block",
+                "drop: c",
+                "drop: b",
+                "drop: a"
+            ]
+        );
+    }
+
+    #[test]
+    fn fields_drop_in_declaration_order() {
+        assert_eq!(
+            events(field_order),
+            [
+                "new: second",
+                "new: first",
+                "end of scope",
+                "drop: first",
+                "drop: second"
+            ]
+        );
+    }
+
+    #[test]
+    fn mem_drop_drops_immediately() {
+        assert_eq!(
+            events(early_drop),
+            ["new: a", "new: b", "drop: a", "end of scope", "drop: b"]
+        );
+    }
+
+    #[test]
+    fn shadowed_value_lives_until_the_end_of_scope() {
+        assert_eq!(
+            events(shadowing),
+            [
+                "new: first",
+                "shadowing first",
+                "new: second",
+                "end of scope, second is visible",
+                "drop: second",
+                "drop: first"
+            ]
+        );
+    }
+
+    #[test]
+    fn reassignment_drops_the_old_value_immediately() {
+        assert_eq!(
+            events(reassignment),
+            [
+                "new: first",
+                "reassigning first",
+                "new: second",
+                "drop: first",
+                "end of scope, second is visible",
+                "drop: second"
+            ]
+        );
+    }
+}
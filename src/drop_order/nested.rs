@@ -0,0 +1,62 @@
+// Nested Scopes ------------------------------------------------
+// Three levels of blocks: inner values are dropped before outer ones, values in the same block in
+// reverse declaration order, and a value returned out of a block is dropped where it ends up instead
+use crate::tracer::{mark, DropLog, Tracer};
+
+/// Runs the nested-scope timeline, recording every creation, drop and scope end into `log`.
+///
+/// `log` is an owned handle, an `Rc` clone of the caller's. Both point at the same Vec, so what
+/// is recorded here is still there for the caller once this handle is dropped with the function.
+pub fn run(log: DropLog) {
+    let _outer = Tracer::new("outer", &log);
+    let escaped = {
+        let _middle = Tracer::new("middle", &log);
+        let escaped = {
+            let _inner_a = Tracer::new("inner a", &log);
+            let inner_b = Tracer::new("inner b", &log);
+            let _inner_c = Tracer::new("inner c", &log);
+            mark(&log, "end of inner block");
+            inner_b // moved out, so it isn't dropped with the rest of the block
+        }; // inner c, inner a
+        mark(
+            &log,
+            &format!("end of middle block, holding {}", escaped.label()),
+        );
+        escaped
+    }; // middle
+    mark(
+        &log,
+        &format!("end of outer scope, holding {}", escaped.label()),
+    );
+} // escaped (inner b), then outer
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracer::new_log;
+    use std::rc::Rc;
+
+    #[test]
+    fn nested_timeline() {
+        let log = new_log();
+        run(Rc::clone(&log));
+        assert_eq!(
+            *log.borrow(),
+            [
+                "new: outer",
+                "new: middle",
+                "new: inner a",
+                "new: inner b",
+                "new: inner c",
+                "end of inner block",
+                "drop: inner c",
+                "drop: inner a",
+                "end of middle block, holding inner b",
+                "drop: middle",
+                "end of outer scope, holding inner b",
+                "drop: inner b",
+                "drop: outer",
+            ]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+// Trait Objects ------------------------------------------------
+// A Vec can only hold one type, `Box<dyn Trait>` lets it hold many: each box owns a value of some
+// type implementing the trait. A slice of `&dyn Trait` does the same without owning anything,
+// so every value it points at has to outlive it
+use std::io::{self, Write};
+use std::iter;
+
+use crate::example::{Example, Rule, RunConfig};
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+/// Copy, so putting it in a collection copies it.
+#[derive(Debug, Clone, Copy)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Owns a String, so putting it in a collection moves it.
+#[derive(Debug)]
+pub struct Tag {
+    pub name: String,
+}
+
+impl Describe for Coord {
+    fn describe(&self) -> String {
+        format!("coord ({}, {})", self.x, self.y)
+    }
+}
+
+impl Describe for Tag {
+    fn describe(&self) -> String {
+        format!("tag #{}", self.name)
+    }
+}
+
+/// Takes ownership of the whole collection, the boxes and the values in them.
+///
+/// Boxing a value moves it into the box, it can't be used through the old binding:
+///
+/// ```compile_fail
+/// use ownership::dyn_demo::{Describe, Tag};
+///
+/// let tag = Tag { name: String::from("rust") };
+/// let mut items: Vec<Box<dyn Describe>> = Vec::new();
+/// items.push(Box::new(tag));
+/// println!("{}", tag.name); // error: borrow of moved value: `tag`
+/// ```
+pub fn describe_all(items: Vec<Box<dyn Describe>>) -> Vec<String> {
+    items.iter().map(|item| item.describe()).collect()
+} // items, and every value it owns, is dropped here
+
+/// Only borrows, the caller keeps every value.
+///
+/// So the values must live at least as long as the references to them:
+///
+/// ```compile_fail
+/// use ownership::dyn_demo::{describe_refs, Describe, Tag};
+///
+/// let refs: Vec<&dyn Describe>;
+/// {
+///     let tag = Tag { name: String::from("rust") };
+///     refs = vec![&tag]; // error: `tag` does not live long enough
+/// }
+/// describe_refs(&refs);
+/// ```
+pub fn describe_refs(items: &[&dyn Describe]) -> Vec<String> {
+    items.iter().map(|item| item.describe()).collect()
+}
+
+// Returning Iterators ------------------------------------------
+// `impl Iterator` returns one concrete (if unnamed) type without boxing it. `Box<dyn Iterator>` costs
+// an allocation but lets one function return different iterator types. Either way, an iterator that
+// outlives the function has to own its data, it can't borrow the function's locals
+
+/// Lazily yields the words of `text`, which the iterator owns.
+///
+/// Borrowing from a local instead would leave the iterator pointing at a dropped String:
+///
+/// ```compile_fail
+/// fn words() -> impl Iterator<Item = &'static str> {
+///     let text = String::from("a b c");
+///     text.split_whitespace() // error: cannot return value referencing local variable `text`
+/// }
+/// ```
+pub fn make_words_impl(text: String) -> impl Iterator<Item = String> {
+    let mut pos = 0;
+    iter::from_fn(move || {
+        let rest = &text[pos..];
+        let start = rest.find(|c: char| !c.is_whitespace())?;
+        let word = &rest[start..];
+        let len = word.find(char::is_whitespace).unwrap_or(word.len());
+        pos += start + len;
+        Some(word[..len].to_string())
+    })
+}
+
+/// The same words, from an iterator type picked at runtime.
+///
+/// Text without any words gets `iter::empty()`, which lets `text` go right away instead of
+/// carrying it around, any other text gets the [`make_words_impl`] splitter. Those are two
+/// different types, and `impl Iterator` stands for exactly one, so only a box can return either.
+pub fn make_words_boxed(text: String) -> Box<dyn Iterator<Item = String>> {
+    if text.trim_start().is_empty() {
+        Box::new(iter::empty()) // text is dropped here
+    } else {
+        Box::new(make_words_impl(text))
+    }
+}
+
+pub struct DynExample;
+
+impl Example for DynExample {
+    fn name(&self) -> &str {
+        "dyn"
+    }
+
+    fn description(&self) -> &str {
+        "owned Box<dyn Trait> vs borrowed &dyn Trait"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("dyn_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let origin = Coord { x: 0, y: 0 };
+        let tag = Tag {
+            name: String::from("ownership"),
+        };
+
+        let borrowed = describe_refs(&[&origin, &tag]);
+        writeln!(
+            out,
+            "borrowed: {:?}, tag still ours: {}",
+            borrowed, tag.name
+        )?;
+
+        let owned: Vec<Box<dyn Describe>> = vec![Box::new(origin), Box::new(tag)];
+        let described = describe_all(owned); // origin was copied in, tag was moved in
+        writeln!(
+            out,
+            "owned:    {:?}, origin still usable: {:?}",
+            described, origin
+        )?;
+
+        let words = {
+            let text = String::from("the iterator owns its text");
+            make_words_impl(text)
+        }; // text's scope is over, but it was moved into the iterator
+        writeln!(out, "impl Iterator: {:?}", words.collect::<Vec<_>>())?;
+        writeln!(
+            out,
+            "  one concrete type, no allocation for the iterator itself"
+        )?;
+        let boxed = make_words_boxed(String::from("a b c"));
+        writeln!(out, "Box<dyn Iterator>: {:?}", boxed.collect::<Vec<_>>())?;
+        writeln!(
+            out,
+            "  different iterator types behind one signature, at the cost of a box"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str) -> Tag {
+        Tag {
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn describe_all_owns_a_mixed_collection() {
+        let items: Vec<Box<dyn Describe>> = vec![
+            Box::new(Coord { x: 1, y: 2 }),
+            Box::new(tag("a")),
+            Box::new(Coord { x: -1, y: 0 }),
+        ];
+        assert_eq!(
+            describe_all(items),
+            ["coord (1, 2)", "tag #a", "coord (-1, 0)"]
+        );
+    }
+
+    #[test]
+    fn describe_refs_borrows_a_mixed_collection() {
+        let c = Coord { x: 3, y: 4 };
+        let t = tag("b");
+        assert_eq!(describe_refs(&[&t, &c]), ["tag #b", "coord (3, 4)"]);
+        assert_eq!(t.name, "b");
+    }
+
+    #[test]
+    fn empty_collections() {
+        assert!(describe_all(Vec::new()).is_empty());
+        assert!(describe_refs(&[]).is_empty());
+    }
+
+    #[test]
+    fn both_iterators_yield_the_same_words() {
+        let text = "  the quick\tbrown  fox ";
+        let from_impl: Vec<String> = make_words_impl(text.to_string()).collect();
+        let from_box: Vec<String> = make_words_boxed(text.to_string()).collect();
+        assert_eq!(from_impl, ["the", "quick", "brown", "fox"]);
+        assert_eq!(from_box, from_impl);
+        assert_eq!(make_words_impl(String::from("   ")).count(), 0);
+    }
+
+    #[test]
+    fn iterators_outlive_the_callers_string() {
+        let mut words = {
+            let text = String::from("héllo wörld");
+            make_words_boxed(text)
+        };
+        assert_eq!(words.next().as_deref(), Some("héllo"));
+        assert_eq!(words.next().as_deref(), Some("wörld"));
+        assert_eq!(words.next(), None);
+    }
+
+    #[test]
+    fn boxed_iterator_picks_its_type_at_runtime_but_not_its_words() {
+        for text in [
+            "a b, c",
+            "  spaces  only ",
+            "tabs\tand\nnewlines",
+            "",
+            "   ",
+            "\t\n ",
+        ] {
+            let from_impl: Vec<String> = make_words_impl(text.to_string()).collect();
+            let from_box: Vec<String> = make_words_boxed(text.to_string()).collect();
+            assert_eq!(from_box, from_impl, "{:?}", text);
+        }
+        let words: Vec<String> = make_words_boxed(String::from("a b, c")).collect();
+        assert_eq!(words, ["a", "b,", "c"]);
+    }
+
+    #[test]
+    fn boxed_iterator_is_lazy() {
+        let mut words = make_words_boxed(String::from("first second"));
+        assert_eq!(words.size_hint(), (0, None)); // nothing split up front
+        assert_eq!(words.next().as_deref(), Some("first"));
+    }
+}
@@ -0,0 +1,193 @@
+// Errors That Own Their Context --------------------------------
+// An error can take ownership of the value that caused it, so a function that consumes its input
+// can hand it back on failure instead of losing it. `?` returns the error early and moves it up
+// to the caller, converting it with From on the way if needed
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// The longest input [`process`] accepts, in bytes.
+pub const MAX_LEN: usize = 16;
+
+#[derive(Debug)]
+pub enum ProcessError {
+    Empty,
+    /// The rejected input, moved into the error so the caller gets it back.
+    TooLong {
+        input: String,
+        max: usize,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::Empty => write!(f, "input is empty"),
+            ProcessError::TooLong { input, max } => write!(
+                f,
+                "input is {} bytes, more than the {} allowed",
+                input.len(),
+                max
+            ),
+            ProcessError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {}
+
+impl From<io::Error> for ProcessError {
+    fn from(e: io::Error) -> Self {
+        ProcessError::Io(e)
+    }
+}
+
+fn non_empty(input: String) -> Result<String, ProcessError> {
+    if input.trim().is_empty() {
+        Err(ProcessError::Empty)
+    } else {
+        Ok(input)
+    }
+}
+
+fn short_enough(input: String, max: usize) -> Result<String, ProcessError> {
+    if input.len() > max {
+        Err(ProcessError::TooLong { input, max }) // input moves into the error
+    } else {
+        Ok(input)
+    }
+}
+
+/// Trims and uppercases `input`. On `TooLong` the untouched input is in the error.
+pub fn process(input: String) -> Result<String, ProcessError> {
+    let input = non_empty(input)?;
+    let input = short_enough(input, MAX_LEN)?;
+    Ok(input.trim().to_uppercase())
+}
+
+/// Reads all of `reader` and processes it, an io::Error becoming `ProcessError::Io` through `?`.
+pub fn process_reader(mut reader: impl Read) -> Result<String, ProcessError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    process(input)
+}
+
+/// Processes every input in order, stopping at the first error. The inputs after it are dropped.
+pub fn process_all(inputs: Vec<String>) -> Result<Vec<String>, ProcessError> {
+    let mut processed = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        processed.push(process(input)?); // the error, and the input inside it, move to our caller
+    }
+    Ok(processed)
+}
+
+pub struct ErrorsExample;
+
+impl Example for ErrorsExample {
+    fn name(&self) -> &str {
+        "errors"
+    }
+
+    fn description(&self) -> &str {
+        "an error that owns its input, moved up with ?"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("errors.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        writeln!(
+            out,
+            "process(\" one owner \") = {:?}",
+            process(String::from(" one owner ")).ok()
+        )?;
+        match process(String::from("far too long for the limit")) {
+            Ok(s) => writeln!(out, "unexpected {}", s)?,
+            Err(ProcessError::TooLong { input, max }) => {
+                let recovered = input[..max].to_string();
+                writeln!(
+                    out,
+                    "TooLong gave the input back: {:?}, kept the first {} bytes: {:?}",
+                    input, max, recovered
+                )?
+            }
+            Err(e) => writeln!(out, "error: {}", e)?,
+        }
+        let inputs = vec![String::from("a"), String::new(), String::from("never")];
+        match process_all(inputs) {
+            Ok(all) => writeln!(out, "process_all = {:?}", all),
+            Err(e) => writeln!(out, "process_all stopped early: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn happy_path() {
+        assert_eq!(process(String::from("  hello ")).unwrap(), "HELLO");
+        let all = process_all(vec![String::from("a"), String::from("b")]).unwrap();
+        assert_eq!(all, ["A", "B"]);
+        assert!(process_all(Vec::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn too_long_hands_the_input_back() {
+        let input = "x".repeat(MAX_LEN + 1);
+        let ptr = input.as_ptr();
+        match process(input) {
+            Err(ProcessError::TooLong { input, max }) => {
+                assert_eq!(input, "x".repeat(MAX_LEN + 1));
+                assert_eq!(input.as_ptr(), ptr); // the same buffer, moved and not copied
+                assert_eq!(max, MAX_LEN);
+            }
+            other => panic!("expected TooLong, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn empty_input_is_rejected() {
+        assert!(matches!(process(String::new()), Err(ProcessError::Empty)));
+        assert!(matches!(
+            process(String::from("  ")),
+            Err(ProcessError::Empty)
+        ));
+    }
+
+    #[test]
+    fn process_all_returns_the_first_error() {
+        let long = "y".repeat(MAX_LEN * 2);
+        let inputs = vec![String::from("ok"), long.clone(), String::new()];
+        match process_all(inputs) {
+            Err(ProcessError::TooLong { input, .. }) => assert_eq!(input, long),
+            other => panic!("expected TooLong, got {:?}", other),
+        }
+    }
+
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn io_errors_convert_through_from() {
+        let e = process_reader(Broken).unwrap_err();
+        assert!(matches!(e, ProcessError::Io(_)));
+        assert_eq!(e.to_string(), "disk on fire");
+        let e: ProcessError = io::Error::other("direct").into();
+        assert!(matches!(e, ProcessError::Io(_)));
+        assert_eq!(process_reader("read me".as_bytes()).unwrap(), "READ ME");
+    }
+}
@@ -0,0 +1,276 @@
+// Example: a single runnable demo of one concept
+use std::io::{self, Write};
+
+#[cfg(feature = "count-allocs")]
+use crate::alloc_count;
+use crate::arena;
+use crate::arrays;
+#[cfg(feature = "unsafe-demos")]
+use crate::borrowing::dangling;
+use crate::borrowing::{mutable, shared, split};
+use crate::box_demo;
+use crate::builder;
+use crate::callbacks;
+use crate::capacity;
+use crate::cell_demo;
+use crate::closures;
+use crate::control_flow;
+use crate::conversions;
+use crate::copy_clone_types;
+use crate::cow_demo;
+use crate::cycles;
+use crate::deep_clone;
+use crate::demo_result::DemoResult;
+use crate::document;
+use crate::drop_order;
+use crate::dyn_demo;
+use crate::errors;
+use crate::frozen_collections;
+use crate::graph;
+use crate::guard;
+use crate::inspect;
+use crate::interior_mutability;
+use crate::invalidation;
+use crate::iterators;
+use crate::lifetimes::{self, static_demo};
+use crate::list;
+use crate::loops;
+use crate::maps;
+use crate::mem_tricks;
+use crate::mybox;
+use crate::newtype;
+use crate::no_drop;
+use crate::observers;
+use crate::option_moves;
+use crate::out_params;
+use crate::output::Style;
+use crate::ownership::{clone_copy, functions, moves, pass_through, strings};
+use crate::partial_moves;
+use crate::patterns;
+use crate::pin_demo;
+use crate::raii;
+use crate::rc_cow;
+use crate::rc_demo;
+use crate::refcell_demo;
+use crate::self_ref;
+use crate::shadowing;
+use crate::shared_strings;
+use crate::slices;
+use crate::statics;
+use crate::string_building;
+use crate::threads::{channels, move_closures, rwlock_demo, scoped, shared_state};
+use crate::tree;
+use crate::typestate;
+use crate::utf8::{self, iteration};
+use crate::vec_ownership;
+
+/// The ownership rule an example demonstrates, numbered as in the header of `lib.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// 1. Each value in Rust has a variable that’s called its owner.
+    Owner,
+    /// 2. There can only be one owner at a time.
+    OneOwner,
+    /// 3. When the owner goes out of scope, the value will be dropped.
+    DropAtScopeEnd,
+}
+
+impl Rule {
+    pub fn number(self) -> u8 {
+        match self {
+            Rule::Owner => 1,
+            Rule::OneOwner => 2,
+            Rule::DropAtScopeEnd => 3,
+        }
+    }
+}
+
+/// How an example should run, built from the command line flags.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunConfig {
+    /// Print the pointer, length and capacity of a String after every change to it.
+    pub verbose: bool,
+    pub style: Style,
+}
+
+impl RunConfig {
+    /// In verbose mode, writes `label` followed by what [`inspect::describe`] says about `s`.
+    #[allow(clippy::ptr_arg)] // capacity is the point, a &str doesn't have one
+    pub fn trace(&self, out: &mut dyn Write, label: &str, s: &String) -> io::Result<()> {
+        if self.verbose {
+            writeln!(out, "  [{}] {}", label, inspect::describe(s))?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Example {
+    /// Short, unique identifier of the example, e.g. `moves`.
+    fn name(&self) -> &str;
+    /// One-line summary of what the example demonstrates.
+    fn description(&self) -> &str;
+    /// The ownership rule the example demonstrates.
+    fn rule(&self) -> Rule;
+    /// The source file the example lives in, usually `include_str!("example.rs")`.
+    fn source(&self) -> &'static str;
+    /// Runs the walkthrough, writing its output to `out`.
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()>;
+    /// Runs the walkthrough and returns its steps along with its text, for the demos that record them.
+    fn result(&self, _config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        Ok(None)
+    }
+}
+
+/// Every example in walkthrough order.
+pub fn registry() -> Vec<Box<dyn Example>> {
+    vec![
+        Box::new(strings::StringsExample),
+        Box::new(moves::MovesExample),
+        Box::new(clone_copy::CloneExample),
+        Box::new(clone_copy::CopyExample),
+        Box::new(functions::FunctionsExample),
+        Box::new(shared::ReferencesExample),
+        Box::new(mutable::MutableReferencesExample),
+        Box::new(split::SplitBorrowsExample),
+        Box::new(slices::SlicesExample),
+        Box::new(arrays::ArraysExample),
+        Box::new(drop_order::DropOrderExample),
+        Box::new(rc_demo::RcExample),
+        Box::new(shared_strings::SharedStringsExample),
+        Box::new(cycles::CyclesExample),
+        Box::new(refcell_demo::RefCellExample),
+        Box::new(cell_demo::CellExample),
+        Box::new(interior_mutability::InteriorMutabilityExample),
+        Box::new(box_demo::BoxExample),
+        Box::new(shared_state::SharedStateExample),
+        Box::new(rwlock_demo::RwLockExample),
+        Box::new(scoped::ScopedThreadsExample),
+        Box::new(channels::ChannelsExample),
+        Box::new(move_closures::MoveClosuresExample),
+        Box::new(lifetimes::LifetimesExample),
+        Box::new(document::DocumentExample),
+        Box::new(self_ref::SelfRefExample),
+        Box::new(pin_demo::PinExample),
+        Box::new(static_demo::StaticExample),
+        Box::new(statics::StaticsExample),
+        Box::new(partial_moves::PartialMovesExample),
+        Box::new(patterns::PatternsExample),
+        Box::new(option_moves::OptionMovesExample),
+        Box::new(control_flow::ControlFlowExample),
+        Box::new(mem_tricks::MemTricksExample),
+        Box::new(cow_demo::CowExample),
+        Box::new(rc_cow::RcCowExample),
+        Box::new(copy_clone_types::CopyCloneTypesExample),
+        Box::new(deep_clone::DeepCloneExample),
+        Box::new(closures::ClosuresExample),
+        Box::new(callbacks::CallbacksExample),
+        Box::new(iterators::IteratorsExample),
+        Box::new(loops::LoopsExample),
+        Box::new(vec_ownership::VecOwnershipExample),
+        Box::new(invalidation::InvalidationExample),
+        Box::new(frozen_collections::FrozenCollectionsExample),
+        Box::new(maps::MapsExample),
+        Box::new(string_building::StringBuildingExample),
+        Box::new(capacity::CapacityExample),
+        Box::new(utf8::Utf8Example),
+        Box::new(iteration::Utf8IterationExample),
+        Box::new(shadowing::ShadowingExample),
+        Box::new(inspect::InspectExample),
+        Box::new(pass_through::PassThroughExample),
+        Box::new(out_params::OutParamsExample),
+        Box::new(no_drop::NoDropExample),
+        #[cfg(feature = "unsafe-demos")]
+        Box::new(dangling::DanglingExample),
+        Box::new(raii::RaiiExample),
+        Box::new(guard::GuardExample),
+        Box::new(arena::ArenaExample),
+        Box::new(list::ListExample),
+        Box::new(tree::TreeExample),
+        Box::new(graph::GraphExample),
+        Box::new(observers::ObserversExample),
+        Box::new(builder::BuilderExample),
+        Box::new(typestate::TypestateExample),
+        Box::new(newtype::NewtypeExample),
+        Box::new(conversions::ConversionsExample),
+        Box::new(dyn_demo::DynExample),
+        Box::new(mybox::MyBoxExample),
+        Box::new(errors::ErrorsExample),
+        #[cfg(feature = "count-allocs")]
+        Box::new(alloc_count::AllocCountExample),
+    ]
+}
+
+/// Display title of an example, `mutable-references` becomes `Mutable references`.
+pub fn title(example: &dyn Example) -> String {
+    let name = example.name().replace('-', " ");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+/// Looks up a registered example by its name.
+pub fn find(name: &str) -> Option<Box<dyn Example>> {
+    registry().into_iter().find(|e| e.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn registry_has_no_duplicate_names() {
+        let examples = registry();
+        let names: HashSet<&str> = examples.iter().map(|e| e.name()).collect();
+        assert_eq!(names.len(), examples.len());
+    }
+
+    #[test]
+    fn find_returns_the_named_example() {
+        assert_eq!(find("moves").unwrap().name(), "moves");
+        assert!(find("nope").is_none());
+    }
+
+    #[test]
+    fn every_example_writes_output() {
+        for example in registry() {
+            let mut out = Vec::new();
+            example.run(&mut out, &RunConfig::default()).unwrap();
+            assert!(!out.is_empty(), "{} wrote nothing", example.name());
+        }
+    }
+
+    #[test]
+    fn results_render_what_run_writes() {
+        let config = RunConfig::default();
+        let mut with_results = Vec::new();
+        for example in registry() {
+            if let Some(result) = example.result(&config).unwrap() {
+                let mut out = Vec::new();
+                example.run(&mut out, &config).unwrap();
+                assert_eq!(result.output.as_bytes(), out, "{}", example.name());
+                assert_eq!(result.name, example.name());
+                with_results.push(result.name);
+            }
+        }
+        assert_eq!(
+            with_results,
+            ["moves", "clone", "copy", "functions", "references"]
+        );
+    }
+
+    #[test]
+    fn every_source_is_the_file_defining_the_example() {
+        for example in registry() {
+            let source = example.source();
+            assert!(source.contains("impl Example for "), "{}", example.name());
+            assert!(
+                source.contains(&format!("\"{}\"", example.name())),
+                "{}",
+                example.name()
+            );
+        }
+    }
+}
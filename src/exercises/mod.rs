@@ -0,0 +1,176 @@
+// Exercises ----------------------------------------------------
+// Stubs to fill in, in stubs.rs, one per ownership pattern the examples walk through. The tests
+// below say what each one should do; they are ignored until you start, run them with
+// `cargo test -- --ignored exercises` and `cargo run -- --exercises` lists the prompts
+
+pub struct Exercise {
+    pub name: &'static str,
+    pub prompt: &'static str,
+}
+
+/// Every exercise in the order they are meant to be done.
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "fix_double_move",
+        prompt: "Return two Strings equal to `s`. The obvious `(s, s)` moves `s` twice.",
+    },
+    Exercise {
+        name: "take_ownership_and_report",
+        prompt: "Take `s`, and hand it back together with its length in bytes.",
+    },
+    Exercise {
+        name: "append_twice",
+        prompt: "Append `suffix` to `s` twice, in place, without returning anything.",
+    },
+    Exercise {
+        name: "longest_word",
+        prompt: "Return a reference to the longest word, the first one on a tie. `words` is never empty.",
+    },
+    Exercise {
+        name: "first_word",
+        prompt: "Return the slice of `s` up to its first space, or all of `s` if there is none.",
+    },
+    Exercise {
+        name: "swap_names",
+        prompt: "Swap the contents of `a` and `b` without cloning either of them.",
+    },
+    Exercise {
+        name: "take_if_long",
+        prompt: "Move the String out of `slot` if it holds one at least `min` bytes long, leaving None.",
+    },
+    Exercise {
+        name: "split_owned",
+        prompt: "Split `s` on whitespace into owned words. The words must outlive `s`.",
+    },
+    Exercise {
+        name: "share",
+        prompt: "Return `n` handles to a single shared copy of `s`, without cloning the String.",
+    },
+];
+
+// Learners get the stubs, `--features solutions` swaps in the reference implementations
+#[cfg(feature = "solutions")]
+mod solutions;
+#[cfg(not(feature = "solutions"))]
+mod stubs;
+
+#[cfg(feature = "solutions")]
+pub use solutions::*;
+#[cfg(not(feature = "solutions"))]
+pub use stubs::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn every_stub_has_a_prompt() {
+        assert_eq!(EXERCISES.len(), 9);
+        for exercise in EXERCISES {
+            assert!(!exercise.prompt.is_empty(), "{}", exercise.name);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "solutions"))]
+    fn stubs_are_not_implemented_yet() {
+        let result = std::panic::catch_unwind(|| fix_double_move(String::from("hello")));
+        let message = result.unwrap_err();
+        assert!(message
+            .downcast_ref::<&str>()
+            .is_some_and(|m| m.starts_with("not yet implemented")));
+    }
+
+    #[test]
+    #[ignore]
+    fn fix_double_move_returns_two_copies() {
+        let (a, b) = fix_double_move(String::from("hello"));
+        assert_eq!(a, "hello");
+        assert_eq!(b, "hello");
+        assert_ne!(a.as_ptr(), b.as_ptr());
+    }
+
+    #[test]
+    #[ignore]
+    fn take_ownership_and_report_hands_the_string_back() {
+        let s = String::from("héllo");
+        let ptr = s.as_ptr();
+        let (s, len) = take_ownership_and_report(s);
+        assert_eq!(len, 6);
+        assert_eq!(s.as_ptr(), ptr); // the same buffer, not a copy
+    }
+
+    #[test]
+    #[ignore]
+    fn append_twice_grows_in_place() {
+        let mut s = String::from("ab");
+        append_twice(&mut s, "cd");
+        assert_eq!(s, "abcdcd");
+        append_twice(&mut s, "");
+        assert_eq!(s, "abcdcd");
+    }
+
+    #[test]
+    #[ignore]
+    fn longest_word_borrows_from_the_slice() {
+        let words: Vec<String> = ["tie", "one", "three", "seven"].map(String::from).into();
+        let longest = longest_word(&words);
+        assert_eq!(longest, "three");
+        assert!(std::ptr::eq(longest, &words[2]));
+        assert_eq!(longest_word(&words[..2]), "tie");
+    }
+
+    #[test]
+    #[ignore]
+    fn first_word_slices_without_allocating() {
+        let s = String::from("hello world");
+        assert_eq!(first_word(&s), "hello");
+        assert_eq!(first_word(&s).as_ptr(), s.as_ptr());
+        assert_eq!(first_word("single"), "single");
+        assert_eq!(first_word(""), "");
+    }
+
+    #[test]
+    #[ignore]
+    fn swap_names_moves_the_buffers() {
+        let mut a = String::from("alice");
+        let mut b = String::from("bob");
+        let (pa, pb) = (a.as_ptr(), b.as_ptr());
+        swap_names(&mut a, &mut b);
+        assert_eq!((a.as_str(), b.as_str()), ("bob", "alice"));
+        assert_eq!((a.as_ptr(), b.as_ptr()), (pb, pa));
+    }
+
+    #[test]
+    #[ignore]
+    fn take_if_long_only_takes_long_strings() {
+        let mut slot = Some(String::from("hi"));
+        assert_eq!(take_if_long(&mut slot, 3), None);
+        assert_eq!(slot.as_deref(), Some("hi"));
+        assert_eq!(take_if_long(&mut slot, 2).as_deref(), Some("hi"));
+        assert_eq!(slot, None);
+        assert_eq!(take_if_long(&mut slot, 0), None);
+    }
+
+    #[test]
+    #[ignore]
+    fn split_owned_words_outlive_the_input() {
+        let words = {
+            let s = String::from("  owned\twords here ");
+            split_owned(s)
+        };
+        assert_eq!(words, ["owned", "words", "here"]);
+        assert!(split_owned(String::new()).is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn share_hands_out_one_allocation() {
+        let handles = share(String::from("shared"), 3);
+        assert_eq!(handles.len(), 3);
+        assert!(handles.iter().all(|h| Rc::ptr_eq(h, &handles[0])));
+        assert_eq!(Rc::strong_count(&handles[0]), 3);
+        assert!(share(String::from("none"), 0).is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+// Exercise Solutions -------------------------------------------
+// Reference implementations, compiled instead of the stubs with `--features solutions`, so
+// `cargo test --features solutions -- --ignored exercises` shows every test can pass
+#![allow(clippy::ptr_arg)] // the signatures have to match the stubs
+
+use std::mem;
+use std::rc::Rc;
+
+pub fn fix_double_move(s: String) -> (String, String) {
+    (s.clone(), s)
+}
+
+pub fn take_ownership_and_report(s: String) -> (String, usize) {
+    let len = s.len();
+    (s, len)
+}
+
+pub fn append_twice(s: &mut String, suffix: &str) {
+    s.push_str(suffix);
+    s.push_str(suffix);
+}
+
+pub fn longest_word(words: &[String]) -> &String {
+    let mut longest = &words[0];
+    for word in &words[1..] {
+        if word.len() > longest.len() {
+            longest = word;
+        }
+    }
+    longest
+}
+
+pub fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+pub fn swap_names(a: &mut String, b: &mut String) {
+    mem::swap(a, b);
+}
+
+pub fn take_if_long(slot: &mut Option<String>, min: usize) -> Option<String> {
+    match slot {
+        Some(s) if s.len() >= min => slot.take(),
+        _ => None,
+    }
+}
+
+pub fn split_owned(s: String) -> Vec<String> {
+    s.split_whitespace().map(String::from).collect()
+}
+
+pub fn share(s: String, n: usize) -> Vec<Rc<String>> {
+    let shared = Rc::new(s);
+    (0..n).map(|_| Rc::clone(&shared)).collect()
+}
@@ -0,0 +1,42 @@
+// Exercise Stubs -----------------------------------------------
+// Replace each todo!() until `cargo test -- --ignored exercises` passes. The parameters stay
+// unused until then, and the signatures are part of the exercise
+#![allow(unused_variables, clippy::ptr_arg)]
+
+use std::rc::Rc;
+
+pub fn fix_double_move(s: String) -> (String, String) {
+    todo!()
+}
+
+pub fn take_ownership_and_report(s: String) -> (String, usize) {
+    todo!()
+}
+
+pub fn append_twice(s: &mut String, suffix: &str) {
+    todo!()
+}
+
+pub fn longest_word(words: &[String]) -> &String {
+    todo!()
+}
+
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
+
+pub fn swap_names(a: &mut String, b: &mut String) {
+    todo!()
+}
+
+pub fn take_if_long(slot: &mut Option<String>, min: usize) -> Option<String> {
+    todo!()
+}
+
+pub fn split_owned(s: String) -> Vec<String> {
+    todo!()
+}
+
+pub fn share(s: String, n: usize) -> Vec<Rc<String>> {
+    todo!()
+}
@@ -0,0 +1,92 @@
+// Markdown Export ----------------------------------------------
+// `cargo run -- export --out docs/` writes one page per example: its description, the source file
+// it lives in and the output of a run, so the walkthrough can be read without a terminal
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::example::{registry, title, Example, RunConfig};
+use crate::json;
+
+/// The pages an export wrote, in registry order.
+#[derive(Debug, Default)]
+pub struct ExportSummary {
+    pub files: Vec<PathBuf>,
+}
+
+/// A fenced code block, closed on a line of its own even if `text` has no trailing newline.
+fn fenced(lang: &str, text: &str) -> String {
+    let newline = if text.ends_with('\n') { "" } else { "\n" };
+    format!("```{}\n{}{}```\n", lang, text, newline)
+}
+
+/// The Markdown page of `example`, with `output` as its captured run.
+pub fn page(example: &dyn Example, output: &str) -> String {
+    format!(
+        "# {}\n\n{}\n\nDemonstrates ownership rule {}.\n\n## Source\n\n{}\n## Output\n\n{}",
+        title(example),
+        example.description(),
+        example.rule().number(),
+        fenced("rust", example.source()),
+        fenced("text", output)
+    )
+}
+
+/// Writes `<name>.md` for every registered example into `dir`, creating it if needed and
+/// overwriting pages from an earlier export.
+pub fn export(dir: &Path, config: &RunConfig) -> io::Result<ExportSummary> {
+    fs::create_dir_all(dir)?;
+    let mut summary = ExportSummary::default();
+    for example in registry() {
+        let result = json::result_of(example.as_ref(), config)?;
+        let path = dir.join(format!("{}.md", example.name()));
+        fs::write(&path, page(example.as_ref(), &result.output))?;
+        summary.files.push(path);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raii::TempDir;
+
+    #[test]
+    fn writes_one_page_per_example() {
+        let dir = TempDir::new().unwrap();
+        let docs = dir.path().join("docs");
+        let summary = export(&docs, &RunConfig::default()).unwrap();
+        let examples = registry();
+        assert_eq!(summary.files.len(), examples.len());
+        for example in &examples {
+            assert!(docs.join(format!("{}.md", example.name())).is_file());
+        }
+        assert_eq!(fs::read_dir(&docs).unwrap().count(), examples.len());
+    }
+
+    #[test]
+    fn page_has_the_source_and_the_output() {
+        let dir = TempDir::new().unwrap();
+        export(dir.path(), &RunConfig::default()).unwrap();
+        let page = fs::read_to_string(dir.path().join("moves.md")).unwrap();
+        assert!(page.starts_with("# Moves\n\nassigning a String moves ownership\n"));
+        assert!(page.contains("```rust\n// Ways Variables and Data Interact: Move"));
+        assert!(page.contains("pub fn move_string() -> String {"));
+        assert!(page.contains("```text\nx = 5, y = 5\ns2 = hello (s1 was moved into s2)\n"));
+    }
+
+    #[test]
+    fn fences_close_on_their_own_line() {
+        assert_eq!(fenced("text", "a\n"), "```text\na\n```\n");
+        assert_eq!(fenced("text", "a"), "```text\na\n```\n");
+    }
+
+    #[test]
+    fn export_overwrites_earlier_pages() {
+        let dir = TempDir::new().unwrap();
+        let stale = dir.path().join("moves.md");
+        fs::write(&stale, "stale").unwrap();
+        export(dir.path(), &RunConfig::default()).unwrap();
+        assert!(fs::read_to_string(stale).unwrap().starts_with("# Moves"));
+    }
+}
@@ -0,0 +1,114 @@
+// Frozen Collections -------------------------------------------
+// Once a Vec is done growing, `Box<[T]>` keeps exactly its elements: no spare capacity, no push,
+// one pointer and a length. `Arc<[T]>` is the same slice behind a shared count, so handing it to
+// another owner copies a pointer instead of every String
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Gives up the spare capacity of `v` and the ability to grow, keeping every String as it is.
+///
+/// A boxed slice has a fixed length, there is nothing to push to:
+///
+/// ```compile_fail
+/// use ownership::frozen_collections::freeze;
+///
+/// let mut frozen = freeze(vec![String::from("a")]);
+/// frozen.push(String::from("b")); // error: no method named `push` found for struct `Box<[String]>`
+/// ```
+pub fn freeze(v: Vec<String>) -> Box<[String]> {
+    v.into_boxed_slice() // reallocates only if there was spare capacity to drop
+}
+
+/// Moves the Strings into one shared slice, which every clone of the Arc points at.
+pub fn share(v: Vec<String>) -> Arc<[String]> {
+    Arc::from(v)
+}
+
+fn names() -> Vec<String> {
+    let mut v = Vec::new();
+    for name in ["ana", "bo", "cy"] {
+        v.push(String::from(name));
+    }
+    v
+}
+
+pub struct FrozenCollectionsExample;
+
+impl Example for FrozenCollectionsExample {
+    fn name(&self) -> &str {
+        "frozen-collections"
+    }
+
+    fn description(&self) -> &str {
+        "a finished Vec becomes a Box<[T]> or an Arc<[T]>"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("frozen_collections.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let v = names();
+        writeln!(
+            out,
+            "Vec<String>: len {}, capacity {}",
+            v.len(),
+            v.capacity()
+        )?;
+        let frozen = freeze(v);
+        writeln!(
+            out,
+            "Box<[String]>: len {}, no capacity and no push, {} bytes on the stack",
+            frozen.len(),
+            std::mem::size_of_val(&frozen)
+        )?;
+        let shared = share(names());
+        let other = Arc::clone(&shared);
+        writeln!(
+            out,
+            "Arc<[String]>: {:?}, {} owners of one slice, same pointer: {}",
+            other,
+            Arc::strong_count(&shared),
+            Arc::ptr_eq(&shared, &other)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freeze_keeps_the_elements_and_drops_spare_capacity() {
+        let mut v = Vec::with_capacity(16);
+        v.push(String::from("a"));
+        v.push(String::from("b"));
+        let first = v[0].as_ptr();
+        let frozen = freeze(v);
+        assert_eq!(&*frozen, ["a", "b"]);
+        // the Strings themselves were moved, not cloned
+        assert_eq!(frozen[0].as_ptr(), first);
+        assert_eq!(frozen.into_vec().capacity(), 2);
+    }
+
+    #[test]
+    fn empty_vectors_freeze_and_share() {
+        assert!(freeze(Vec::new()).is_empty());
+        assert!(share(Vec::with_capacity(8)).is_empty());
+    }
+
+    #[test]
+    fn shared_clones_are_pointer_equal() {
+        let shared = share(names());
+        let clone = Arc::clone(&shared);
+        assert!(Arc::ptr_eq(&shared, &clone));
+        assert_eq!(Arc::strong_count(&shared), 2);
+        assert_eq!(&*clone, ["ana", "bo", "cy"]);
+    }
+}
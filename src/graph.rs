@@ -0,0 +1,183 @@
+// Graphs with Indices ------------------------------------------
+// When references fight the borrow checker, use indices. The graph owns every node in one Vec and
+// an edge is just a pair of positions in it, so cycles need no Rc, no Weak and no RefCell
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Position of a node in the graph that created it.
+///
+/// A newtype rather than a bare `usize`, so an unrelated number can't be passed by mistake:
+///
+/// ```compile_fail
+/// use ownership::graph::Graph;
+///
+/// let mut graph = Graph::new();
+/// let a = graph.add_node(String::from("a"));
+/// graph.add_edge(a, 0); // error: mismatched types, expected `NodeId`, found integer
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+pub struct NodeData {
+    pub label: String,
+}
+
+#[derive(Default)]
+pub struct Graph {
+    nodes: Vec<NodeData>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph::default()
+    }
+
+    /// Moves `label` into the graph and returns the id to refer to it by.
+    pub fn add_node(&mut self, label: String) -> NodeId {
+        self.nodes.push(NodeData { label });
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Adds a directed edge from `a` to `b`. Adding the same edge twice keeps both.
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId) {
+        assert!(
+            a.0 < self.nodes.len() && b.0 < self.nodes.len(),
+            "node from another graph"
+        );
+        self.edges.push((a.0, b.0));
+    }
+
+    pub fn label(&self, n: NodeId) -> &str {
+        &self.nodes[n.0].label
+    }
+
+    /// Targets of the edges leaving `n`, in the order the edges were added.
+    pub fn neighbors(&self, n: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges
+            .iter()
+            .filter(move |(from, _)| *from == n.0)
+            .map(|&(_, to)| NodeId(to))
+    }
+
+    /// Every node reachable from `from`, including itself, in breadth-first order.
+    pub fn reachable(&self, from: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([from]);
+        seen[from.0] = true;
+        while let Some(n) = queue.pop_front() {
+            order.push(n);
+            for next in self.neighbors(n) {
+                if !seen[next.0] {
+                    seen[next.0] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+}
+
+pub struct GraphExample;
+
+impl Example for GraphExample {
+    fn name(&self) -> &str {
+        "graph"
+    }
+
+    fn description(&self) -> &str {
+        "indices instead of references for cyclic data"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("graph.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut graph = Graph::new();
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(|l| graph.add_node(String::from(l)));
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a); // a cycle, no Weak needed
+        graph.add_edge(d, a);
+
+        for n in [a, b, c, d] {
+            let neighbors: Vec<&str> = graph.neighbors(n).map(|m| graph.label(m)).collect();
+            writeln!(out, "{} -> {:?}", graph.label(n), neighbors)?;
+        }
+        let reachable: Vec<&str> = graph
+            .reachable(a)
+            .into_iter()
+            .map(|n| graph.label(n))
+            .collect();
+        writeln!(out, "reachable from a: {:?}", reachable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nodes(graph: &mut Graph, count: usize) -> Vec<NodeId> {
+        (0..count).map(|i| graph.add_node(i.to_string())).collect()
+    }
+
+    #[test]
+    fn neighbors_follow_insertion_order() {
+        let mut graph = Graph::new();
+        let n = nodes(&mut graph, 4);
+        graph.add_edge(n[0], n[3]);
+        graph.add_edge(n[0], n[1]);
+        graph.add_edge(n[1], n[2]);
+        graph.add_edge(n[0], n[2]);
+        assert_eq!(
+            graph.neighbors(n[0]).collect::<Vec<_>>(),
+            [n[3], n[1], n[2]]
+        );
+        assert_eq!(graph.neighbors(n[3]).count(), 0);
+    }
+
+    #[test]
+    fn duplicate_edges_and_self_loops() {
+        let mut graph = Graph::new();
+        let n = nodes(&mut graph, 2);
+        graph.add_edge(n[0], n[1]);
+        graph.add_edge(n[0], n[1]);
+        graph.add_edge(n[1], n[1]);
+        assert_eq!(graph.neighbors(n[0]).collect::<Vec<_>>(), [n[1], n[1]]);
+        assert_eq!(graph.neighbors(n[1]).collect::<Vec<_>>(), [n[1]]);
+        assert_eq!(graph.reachable(n[1]), [n[1]]);
+        assert_eq!(graph.reachable(n[0]), [n[0], n[1]]);
+    }
+
+    #[test]
+    fn reachable_is_breadth_first_and_handles_cycles() {
+        let mut graph = Graph::new();
+        let n = nodes(&mut graph, 6);
+        graph.add_edge(n[0], n[1]);
+        graph.add_edge(n[0], n[2]);
+        graph.add_edge(n[1], n[3]);
+        graph.add_edge(n[2], n[3]);
+        graph.add_edge(n[3], n[0]); // back to the start
+        graph.add_edge(n[4], n[5]); // unreachable from 0
+        assert_eq!(graph.reachable(n[0]), [n[0], n[1], n[2], n[3]]);
+        assert_eq!(graph.reachable(n[4]), [n[4], n[5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "node from another graph")]
+    fn ids_from_a_bigger_graph_are_rejected() {
+        let mut big = Graph::new();
+        let ids = nodes(&mut big, 3);
+        let mut small = Graph::new();
+        let a = small.add_node(String::from("a"));
+        small.add_edge(a, ids[2]);
+    }
+}
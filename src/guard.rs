@@ -0,0 +1,181 @@
+// Deferred Cleanup ---------------------------------------------
+// A guard that owns a closure and runs it in Drop: cleanup code written next to the change it undoes,
+// guaranteed to run however the scope is left, by falling off the end, an early return or a panic
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Runs its closure when dropped, unless cancelled.
+pub struct Defer<F: FnOnce()> {
+    f: Option<F>, // None once cancelled or run
+}
+
+/// Returns a guard that runs `f` when it goes out of scope.
+///
+/// The closure's captures are borrowed for as long as the guard lives, so the usual borrow rules
+/// apply, a value already borrowed mutably can't be captured as well:
+///
+/// ```compile_fail
+/// use ownership::guard::defer;
+///
+/// let mut count = 0;
+/// let counter = &mut count;
+/// let _guard = defer(|| count += 1); // error: cannot borrow `count` as mutable more than once at a time
+/// *counter += 1;
+/// ```
+pub fn defer<F: FnOnce()>(f: F) -> Defer<F> {
+    Defer { f: Some(f) }
+}
+
+impl<F: FnOnce()> Defer<F> {
+    /// Consumes the guard without running the closure.
+    pub fn cancel(mut self) {
+        self.f = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            f();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub verbose: bool,
+}
+
+/// Turns `verbose` on for the duration of the call and restores it on every way out.
+pub fn with_verbose(config: &RefCell<Config>, fail: bool) -> Result<(), String> {
+    let previous = config.borrow().verbose;
+    config.borrow_mut().verbose = true;
+    let _restore = defer(|| config.borrow_mut().verbose = previous);
+
+    if fail {
+        return Err(String::from("gave up early")); // _restore still runs
+    }
+    Ok(())
+}
+
+pub struct GuardExample;
+
+impl Example for GuardExample {
+    fn name(&self) -> &str {
+        "guard"
+    }
+
+    fn description(&self) -> &str {
+        "a defer guard that runs cleanup on drop"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("guard.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let config = RefCell::new(Config { verbose: false });
+        let result = with_verbose(&config, false);
+        writeln!(
+            out,
+            "normal exit: {:?}, verbose afterwards = {}",
+            result,
+            config.borrow().verbose
+        )?;
+        let result = with_verbose(&config, true);
+        writeln!(
+            out,
+            "early return: {:?}, verbose afterwards = {}",
+            result,
+            config.borrow().verbose
+        )?;
+
+        let unwound = RefCell::new(Vec::new());
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            config.borrow_mut().verbose = true;
+            let _restore = defer(|| {
+                config.borrow_mut().verbose = false;
+                unwound.borrow_mut().push("restore verbose");
+            });
+            let _inner = defer(|| unwound.borrow_mut().push("inner guard"));
+            unwound.borrow_mut().push("panicking");
+            // unwinds just like panic!, without printing the panic message to stderr
+            panic::resume_unwind(Box::new("failed halfway"));
+        }));
+        writeln!(
+            out,
+            "panic: caught = {}, verbose afterwards = {}, order: {:?}",
+            result.is_err(),
+            config.borrow().verbose,
+            unwound.borrow()
+        )?;
+
+        let log = RefCell::new(Vec::new());
+        {
+            let _first = defer(|| log.borrow_mut().push("first guard"));
+            let second = defer(|| log.borrow_mut().push("cancelled guard"));
+            let _third = defer(|| log.borrow_mut().push("third guard"));
+            second.cancel();
+        } // guards run in reverse order, like any other values
+        let ran = log.into_inner();
+        writeln!(out, "ran: {:?}", ran)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn runs_on_scope_exit() {
+        let ran = Cell::new(false);
+        {
+            let _guard = defer(|| ran.set(true));
+            assert!(!ran.get());
+        }
+        assert!(ran.get());
+    }
+
+    #[test]
+    fn runs_during_a_panic() {
+        let ran = Cell::new(false);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _guard = defer(|| ran.set(true));
+            panic!("unwinding through the guard");
+        }));
+        assert!(result.is_err());
+        assert!(ran.get());
+    }
+
+    #[test]
+    fn cancel_suppresses_the_closure() {
+        let ran = Cell::new(false);
+        defer(|| ran.set(true)).cancel();
+        assert!(!ran.get());
+    }
+
+    #[test]
+    fn config_is_restored_on_every_exit() {
+        let config = RefCell::new(Config { verbose: false });
+        assert!(with_verbose(&config, false).is_ok());
+        assert!(!config.borrow().verbose);
+        assert!(with_verbose(&config, true).is_err());
+        assert!(!config.borrow().verbose);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _restore = defer(|| config.borrow_mut().verbose = false);
+            config.borrow_mut().verbose = true;
+            panic!("failed halfway");
+        }));
+        assert!(result.is_err());
+        assert!(!config.borrow().verbose);
+    }
+}
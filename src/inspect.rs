@@ -0,0 +1,112 @@
+// Inspecting a String ------------------------------------------
+// A String is a pointer to a heap buffer, a length and a capacity. Printing all three while text is
+// appended shows the capacity grow in jumps and the pointer move whenever the buffer is reallocated
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// How many times [`grows_at`] lets the capacity change before it stops.
+pub const GROWTHS: usize = 4;
+
+/// One line with the pointer, length and capacity of `s`.
+#[allow(clippy::ptr_arg)] // a &str has no capacity, this needs the String itself
+pub fn describe(s: &String) -> String {
+    format!(
+        "ptr {:p}, len {:>2}, capacity {:>2}",
+        s.as_ptr(),
+        s.len(),
+        s.capacity()
+    )
+}
+
+/// Pushes chars into a String that starts with `start_cap` bytes of capacity, recording
+/// `(len, capacity)` after every push until the capacity has changed [`GROWTHS`] times.
+pub fn grows_at(start_cap: usize) -> Vec<(usize, usize)> {
+    let mut s = String::with_capacity(start_cap);
+    let mut steps = vec![(s.len(), s.capacity())];
+    let mut growths = 0;
+    while growths < GROWTHS {
+        let before = s.capacity();
+        s.push('x');
+        if s.capacity() != before {
+            growths += 1;
+        }
+        steps.push((s.len(), s.capacity()));
+    }
+    steps
+}
+
+pub struct InspectExample;
+
+impl Example for InspectExample {
+    fn name(&self) -> &str {
+        "inspect"
+    }
+
+    fn description(&self) -> &str {
+        "pointer, length and capacity while a String grows"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("inspect.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let steps = grows_at(0);
+        let mut capacity = 0;
+        for (len, cap) in steps {
+            if cap != capacity {
+                writeln!(
+                    out,
+                    "reallocated at len {:>2}: capacity {} -> {}",
+                    len, capacity, cap
+                )?;
+                capacity = cap;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_reports_len_and_capacity() {
+        let mut s = String::with_capacity(10);
+        s.push_str("hello");
+        let line = describe(&s);
+        assert!(line.starts_with(&format!("ptr {:p}", s.as_ptr())));
+        assert!(line.ends_with("len  5, capacity 10"));
+    }
+
+    #[test]
+    fn capacity_only_grows_when_len_outgrows_it() {
+        for start in [0, 1, 5, 16] {
+            let steps = grows_at(start);
+            assert_eq!(steps[0], (0, start));
+            for pair in steps.windows(2) {
+                let ((_, prev_cap), (len, cap)) = (pair[0], pair[1]);
+                assert!(cap >= prev_cap);
+                assert!(len <= cap);
+                // a strict increase happens exactly when the push no longer fit
+                assert_eq!(cap > prev_cap, len > prev_cap);
+            }
+        }
+    }
+
+    #[test]
+    fn grows_at_stops_after_the_last_growth() {
+        let steps = grows_at(0);
+        let growths = steps.windows(2).filter(|p| p[1].1 != p[0].1).count();
+        assert_eq!(growths, GROWTHS);
+        let last = steps[steps.len() - 1];
+        let before = steps[steps.len() - 2];
+        assert!(last.1 > before.1);
+    }
+}
@@ -0,0 +1,224 @@
+// RefCell vs Mutex ---------------------------------------------
+// The same cache twice: RefCell for one thread, Mutex for many behind an Arc. Both lend out the
+// map while `f` computes a missing value, so an `f` that calls back into the cache panics with
+// RefCell and deadlocks with Mutex. get_or_compute lets go of the map before calling `f`
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Default)]
+pub struct CacheCell {
+    map: RefCell<HashMap<String, String>>,
+}
+
+impl CacheCell {
+    pub fn new() -> CacheCell {
+        CacheCell::default()
+    }
+
+    /// The cached value for `key`, computed with `f` on a miss.
+    ///
+    /// The map stays mutably borrowed while `f` runs, so an `f` that uses the cache panics with
+    /// "already borrowed".
+    pub fn get_or_insert_with(&self, key: &str, f: impl FnOnce() -> String) -> String {
+        let mut map = self.map.borrow_mut();
+        map.entry(key.to_string()).or_insert_with(f).clone()
+    }
+
+    /// Like [`get_or_insert_with`](Self::get_or_insert_with), but no borrow is held while `f`
+    /// runs, so `f` may use the cache. If `f` inserted `key` itself, that value wins.
+    pub fn get_or_compute(&self, key: &str, f: impl FnOnce() -> String) -> String {
+        if let Some(value) = self.map.borrow().get(key) {
+            return value.clone();
+        } // the shared borrow ends here, before f is called
+        let value = f();
+        self.map
+            .borrow_mut()
+            .entry(key.to_string())
+            .or_insert(value)
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.borrow().is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CacheSync {
+    map: Mutex<HashMap<String, String>>,
+}
+
+impl CacheSync {
+    pub fn new() -> CacheSync {
+        CacheSync::default()
+    }
+
+    /// The cached value for `key`, computed with `f` on a miss.
+    ///
+    /// The lock is held while `f` runs. An `f` that uses the cache would wait for a lock its own
+    /// thread holds, forever: std's Mutex isn't re-entrant and doesn't detect the deadlock. That
+    /// is why the demo never tries it.
+    pub fn get_or_insert_with(&self, key: &str, f: impl FnOnce() -> String) -> String {
+        let mut map = self.map.lock().expect("cache lock poisoned");
+        map.entry(key.to_string()).or_insert_with(f).clone()
+    }
+
+    /// Like [`get_or_insert_with`](Self::get_or_insert_with), but the lock is released while `f`
+    /// runs. Two threads may both compute a missing value, the first to insert it wins.
+    pub fn get_or_compute(&self, key: &str, f: impl FnOnce() -> String) -> String {
+        if let Some(value) = self.map.lock().expect("cache lock poisoned").get(key) {
+            return value.clone();
+        } // the guard is dropped here, before f is called
+        let value = f();
+        self.map
+            .lock()
+            .expect("cache lock poisoned")
+            .entry(key.to_string())
+            .or_insert(value)
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.lock().expect("cache lock poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.lock().expect("cache lock poisoned").is_empty()
+    }
+}
+
+pub struct InteriorMutabilityExample;
+
+impl Example for InteriorMutabilityExample {
+    fn name(&self) -> &str {
+        "interior-mutability"
+    }
+
+    fn description(&self) -> &str {
+        "one cache with RefCell for one thread, Mutex for many"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("interior_mutability.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let cell = CacheCell::new();
+        let miss = cell.get_or_insert_with("rule", || String::from("one owner"));
+        let hit = cell.get_or_insert_with("rule", || String::from("never computed"));
+        writeln!(out, "CacheCell: miss -> {:?}, hit -> {:?}", miss, hit)?;
+        let busy = cell.get_or_insert_with("busy", || {
+            // a nested get_or_insert_with would call borrow_mut here and panic
+            let free = cell.map.try_borrow_mut().is_ok();
+            format!("map free while f runs: {}", free)
+        });
+        writeln!(out, "  get_or_insert_with: {}", busy)?;
+        let nested = cell.get_or_compute("nested", || {
+            let inner = cell.get_or_compute("inner", || String::from("computed first"));
+            format!("built on {:?}", inner)
+        });
+        writeln!(out, "  get_or_compute may re-enter: {}", nested)?;
+
+        let sync = CacheSync::new();
+        let value = sync.get_or_insert_with("rule", || String::from("one owner"));
+        writeln!(
+            out,
+            "CacheSync: {:?}, a re-entrant f would deadlock on the lock it already holds",
+            value
+        )?;
+        let nested = sync.get_or_compute("nested", || {
+            sync.get_or_compute("inner", || String::from("computed first"))
+        });
+        writeln!(out, "  get_or_compute may re-enter: {:?}", nested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn cell_miss_then_hit() {
+        let cache = CacheCell::new();
+        assert_eq!(cache.get_or_insert_with("k", || String::from("v")), "v");
+        assert_eq!(
+            cache.get_or_insert_with("k", || unreachable!("a hit calls nothing")),
+            "v"
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn cell_reentry_panics() {
+        let cache = CacheCell::new();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            cache.get_or_insert_with("outer", || {
+                cache.get_or_insert_with("inner", || String::from("x"))
+            })
+        }));
+        assert!(result.is_err());
+        // the RefMut was dropped while unwinding, the cache is usable again
+        assert!(cache.is_empty());
+        assert_eq!(cache.get_or_insert_with("k", || String::from("v")), "v");
+    }
+
+    #[test]
+    fn cell_get_or_compute_allows_reentry() {
+        let cache = CacheCell::new();
+        let value = cache.get_or_compute("outer", || {
+            cache.get_or_compute("inner", || String::from("i")) + "o"
+        });
+        assert_eq!(value, "io");
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn sync_miss_then_hit() {
+        let cache = CacheSync::new();
+        assert_eq!(cache.get_or_insert_with("k", || String::from("v")), "v");
+        assert_eq!(cache.get_or_insert_with("k", || String::from("w")), "v");
+        assert_eq!(cache.get_or_compute("k", || String::from("w")), "v");
+    }
+
+    #[test]
+    fn sync_cache_stays_consistent_under_threads() {
+        let cache = Arc::new(CacheSync::new());
+        let threads: Vec<_> = (0..8)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..100 {
+                        let key = format!("key{}", i % 20);
+                        let value = cache.get_or_insert_with(&key, || format!("{} by {}", key, t));
+                        assert!(value.starts_with(&key));
+                    }
+                })
+            })
+            .collect();
+        for t in threads {
+            t.join().unwrap();
+        }
+        assert_eq!(cache.len(), 20);
+        // every thread now sees the one value that was inserted first
+        for i in 0..20 {
+            let key = format!("key{}", i);
+            let first = cache.get_or_insert_with(&key, || unreachable!("all keys are cached"));
+            assert_eq!(cache.get_or_compute(&key, || unreachable!()), first);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Iterator Invalidation ----------------------------------------
+// `for s in &v` borrows v until the loop ends, so pushing to or removing from v inside the loop is
+// rejected (tests/compile_fail/push_while_iterating.rs and remove_while_iterating.rs). The fixes
+// finish the iteration before changing v: collect indices first, retain, or walk a snapshot
+use std::io::{self, Write};
+use std::mem;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Removes repeated Strings, keeping the first of each where it was.
+///
+/// The loop walks a snapshot moved out of `v`, so pushing the survivors back into `v` is fine.
+/// `contains` makes it quadratic, which is no problem for the short lists it is meant for.
+pub fn dedup_preserving_order(v: &mut Vec<String>) {
+    let snapshot = mem::take(v);
+    for s in snapshot {
+        if !v.contains(&s) {
+            v.push(s);
+        }
+    }
+}
+
+/// Moves the Strings matching `pred` out of `v` and returns them, both sides keeping their order.
+///
+/// The indices are collected while `v` is only borrowed, and removed afterwards from the back, so
+/// removing one doesn't shift the ones still to come.
+pub fn remove_matching(v: &mut Vec<String>, pred: impl Fn(&str) -> bool) -> Vec<String> {
+    let hits: Vec<usize> = v
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| pred(s))
+        .map(|(i, _)| i)
+        .collect();
+    let mut removed: Vec<String> = hits.into_iter().rev().map(|i| v.remove(i)).collect();
+    removed.reverse();
+    removed
+}
+
+pub struct InvalidationExample;
+
+impl Example for InvalidationExample {
+    fn name(&self) -> &str {
+        "iterator-invalidation"
+    }
+
+    fn description(&self) -> &str {
+        "a Vec can't change while it is being iterated"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("invalidation.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        writeln!(
+            out,
+            "`for s in &v` borrows v for the whole loop, pushing or removing inside it won't compile"
+        )?;
+        let mut words: Vec<String> = ["ana", "bo", "ana", "cy", "bo"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        dedup_preserving_order(&mut words);
+        writeln!(out, "dedup walks a snapshot taken out of v -> {:?}", words)?;
+        let short = remove_matching(&mut words, |w| w.len() < 3);
+        writeln!(
+            out,
+            "remove_matching collected indices first -> kept {:?}, removed {:?}",
+            words, short
+        )?;
+        let mut lines = vec![String::from("a"), String::new(), String::from("b")];
+        lines.retain(|l| !l.is_empty());
+        writeln!(out, "retain drops the rest in one pass -> {:?}", lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::strings;
+
+    fn deduped(words: &[&str]) -> Vec<String> {
+        let mut v = strings(words);
+        dedup_preserving_order(&mut v);
+        v
+    }
+
+    #[test]
+    fn dedup_keeps_the_first_of_each() {
+        assert_eq!(deduped(&["a", "b", "a", "c", "b"]), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn dedup_with_duplicates_at_the_ends() {
+        assert_eq!(deduped(&["x", "y", "z", "x"]), ["x", "y", "z"]);
+        assert_eq!(deduped(&["x", "x", "y", "y"]), ["x", "y"]);
+        assert_eq!(deduped(&["a", "a", "a"]), ["a"]);
+        assert!(deduped(&[]).is_empty());
+    }
+
+    #[test]
+    fn remove_matching_keeps_both_sides_in_order() {
+        let mut v = strings(&["rm1", "keep1", "rm2", "keep2", "rm3"]);
+        let removed = remove_matching(&mut v, |s| s.starts_with("rm"));
+        assert_eq!(removed, ["rm1", "rm2", "rm3"]);
+        assert_eq!(v, ["keep1", "keep2"]);
+    }
+
+    #[test]
+    fn remove_matching_everything() {
+        let mut v = strings(&["a", "b"]);
+        assert_eq!(remove_matching(&mut v, |_| true), ["a", "b"]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn remove_matching_nothing() {
+        let mut v = strings(&["a", "b"]);
+        assert!(remove_matching(&mut v, |_| false).is_empty());
+        assert_eq!(v, ["a", "b"]);
+        assert!(remove_matching(&mut Vec::new(), |_| true).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+// Iterators and Ownership --------------------------------------
+// The same Vec<String> can be walked three ways: iter() borrows each element, iter_mut() borrows each
+// element mutably and into_iter() moves every element out, consuming the Vec
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Walks the Vec with `iter()`, which yields `&String` and leaves the Vec untouched.
+///
+/// The Vec is borrowed for as long as the iterator is alive, so it can't be pushed to meanwhile:
+///
+/// ```compile_fail
+/// let mut v = vec![String::from("a")];
+/// for s in v.iter() {
+///     v.push(s.clone()); // error: cannot borrow `v` as mutable because it is also borrowed as immutable
+/// }
+/// ```
+pub fn lengths(v: &[String]) -> Vec<usize> {
+    v.iter().map(|s| s.len()).collect()
+}
+
+/// Walks the Vec with `iter_mut()`, which yields `&mut String` so each element can be changed in place.
+#[allow(clippy::ptr_arg)] // taking the Vec itself mirrors the iter_mut() call on it
+pub fn shout_all(v: &mut Vec<String>) {
+    for s in v.iter_mut() {
+        *s = s.to_uppercase();
+    }
+}
+
+/// Walks the Vec with `into_iter()`, which yields owned `String`s and uses the Vec up.
+///
+/// After `into_iter()` the original Vec is gone:
+///
+/// ```compile_fail
+/// let v = vec![String::from("a")];
+/// let upper: Vec<String> = v.into_iter().map(|s| s.to_uppercase()).collect();
+/// println!("{:?}", v); // error: borrow of moved value: `v`
+/// ```
+pub fn into_upper(v: Vec<String>) -> Vec<String> {
+    v.into_iter().map(|s| s.to_uppercase()).collect()
+}
+
+pub struct IteratorsExample;
+
+impl Example for IteratorsExample {
+    fn name(&self) -> &str {
+        "iterators"
+    }
+
+    fn description(&self) -> &str {
+        "iter vs iter_mut vs into_iter"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("iterators.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut words = vec![String::from("hello"), String::from("world")];
+        writeln!(
+            out,
+            "iter:      lengths {:?}, words {:?}",
+            lengths(&words),
+            words
+        )?;
+
+        shout_all(&mut words);
+        writeln!(out, "iter_mut:  words {:?}", words)?;
+
+        let lower: Vec<String> = words.into_iter().map(|s| s.to_lowercase()).collect();
+        writeln!(out, "into_iter: {:?} (words has been moved)", lower)?;
+        writeln!(out, "into_upper: {:?}", into_upper(lower))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<String> {
+        vec![String::from("a"), String::from("bb"), String::from("ccc")]
+    }
+
+    #[test]
+    fn lengths_borrows_each_element() {
+        let v = words();
+        assert_eq!(lengths(&v), [1, 2, 3]);
+        assert_eq!(v, words());
+    }
+
+    #[test]
+    fn shout_all_changes_elements_in_place() {
+        let mut v = words();
+        shout_all(&mut v);
+        assert_eq!(v, ["A", "BB", "CCC"]);
+    }
+
+    #[test]
+    fn into_upper_moves_elements_out() {
+        let v = words();
+        let ptr = v[2].as_ptr();
+        let upper = into_upper(v);
+        assert_eq!(upper, ["A", "BB", "CCC"]);
+        assert_ne!(upper[2].as_ptr(), ptr); // to_uppercase builds new Strings
+        assert!(into_upper(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+// JSON Output --------------------------------------------------
+// What `--format json` prints: example metadata for `--list` and DemoResults for runs, each
+// object tagged with the schema version so a frontend can tell when the shape changes
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::demo_result::DemoResult;
+use crate::example::{Example, RunConfig};
+
+/// Bumped whenever a field is renamed or removed; new fields don't change it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// `data` with a `schema_version` field next to its own fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(data: T) -> Versioned<T> {
+        Versioned {
+            schema_version: SCHEMA_VERSION,
+            data,
+        }
+    }
+}
+
+/// One entry of `--list --format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExampleInfo {
+    pub name: String,
+    pub description: String,
+    /// The number of the ownership rule, 1 to 3.
+    pub rule: u8,
+}
+
+impl ExampleInfo {
+    pub fn of(example: &dyn Example) -> ExampleInfo {
+        ExampleInfo {
+            name: example.name().to_string(),
+            description: example.description().to_string(),
+            rule: example.rule().number(),
+        }
+    }
+}
+
+/// The example's DemoResult, or one with only its text for examples that don't record steps.
+pub fn result_of(example: &dyn Example, config: &RunConfig) -> io::Result<DemoResult> {
+    match example.result(config)? {
+        Some(result) => Ok(result),
+        None => {
+            let mut result = DemoResult::new(example.name());
+            example.run(&mut result, config)?;
+            Ok(result)
+        }
+    }
+}
+
+/// Writes `value` as pretty-printed JSON followed by a newline.
+pub fn write<T: Serialize>(out: &mut dyn Write, value: &T) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value)?;
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example::find;
+
+    #[test]
+    fn versioned_flattens_the_data() {
+        let info = ExampleInfo::of(find("moves").unwrap().as_ref());
+        let json = serde_json::to_value(Versioned::new(info)).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["name"], "moves");
+        assert_eq!(json["rule"], 2);
+    }
+
+    #[test]
+    fn examples_without_steps_still_have_their_text() {
+        let config = RunConfig::default();
+        let example = find("slices").unwrap();
+        let result = result_of(example.as_ref(), &config).unwrap();
+        assert!(result.steps.is_empty());
+        let mut out = Vec::new();
+        example.run(&mut out, &config).unwrap();
+        assert_eq!(result.output.as_bytes(), out);
+    }
+
+    #[test]
+    fn results_round_trip() {
+        let result = result_of(find("moves").unwrap().as_ref(), &RunConfig::default()).unwrap();
+        let json = serde_json::to_string(&Versioned::new(&result)).unwrap();
+        let back: Versioned<DemoResult> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.data, result);
+    }
+}
@@ -0,0 +1,81 @@
+// OWNERSHIP RULES:
+// 1. Each value in Rust has a variable that’s called its owner.
+// 2. There can only be one owner at a time.
+// 3. When the owner goes out of scope, the value will be dropped.
+
+// keywords: move, drop, clone, borrow, slice
+#[cfg(feature = "count-allocs")]
+pub mod alloc_count;
+pub mod arena;
+pub mod arrays;
+pub mod borrowing;
+pub mod box_demo;
+pub mod builder;
+pub mod callbacks;
+pub mod capacity;
+pub mod cell_demo;
+pub mod cli;
+pub mod closures;
+pub mod control_flow;
+pub mod conversions;
+pub mod copy_clone_types;
+pub mod costs;
+pub mod cow_demo;
+pub mod cycles;
+pub mod deep_clone;
+pub mod demo_result;
+pub mod document;
+pub mod drop_order;
+pub mod dyn_demo;
+pub mod errors;
+pub mod example;
+pub mod exercises;
+pub mod export;
+pub mod frozen_collections;
+pub mod graph;
+pub mod guard;
+pub mod inspect;
+pub mod interior_mutability;
+pub mod invalidation;
+pub mod iterators;
+pub mod json;
+pub mod lifetimes;
+pub mod list;
+pub mod loops;
+pub mod maps;
+pub mod mem_tricks;
+pub mod mybox;
+pub mod newtype;
+pub mod no_drop;
+pub mod observers;
+pub mod option_moves;
+pub mod out_params;
+pub mod output;
+pub mod ownership;
+pub mod partial_moves;
+pub mod patterns;
+pub mod pin_demo;
+pub mod progress;
+pub mod puzzles;
+pub mod quiz;
+pub mod raii;
+pub mod rc_cow;
+pub mod rc_demo;
+pub mod refcell_demo;
+pub mod report;
+pub mod rng;
+pub mod scope_tracker;
+pub mod self_ref;
+pub mod shadowing;
+pub mod shared_strings;
+pub mod slices;
+pub mod statics;
+pub mod string_building;
+#[cfg(test)]
+mod test_util;
+pub mod threads;
+pub mod tracer;
+pub mod tree;
+pub mod typestate;
+pub mod utf8;
+pub mod vec_ownership;
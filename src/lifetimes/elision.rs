@@ -0,0 +1,107 @@
+// Lifetime Elision ------------------------------------------------
+// Most signatures don't need lifetime annotations because the compiler fills them in:
+// 1. every input reference gets its own lifetime parameter
+// 2. if there is exactly one input lifetime, it's given to every output reference
+// 3. if one of the inputs is `&self` or `&mut self`, its lifetime is given to every output reference
+// Each function below has a twin spelling out what the compiler infers.
+
+// Rule 1 only: one lifetime per input, no output reference to assign it to.
+// Twin of borrowing::shared::calculate_length_ref
+#[allow(clippy::ptr_arg, clippy::needless_lifetimes)]
+pub fn calculate_length_ref_explicit<'a>(s: &'a String) -> usize {
+    s.len()
+}
+
+// Rules 1 and 2: a single input lifetime flows to the output
+pub fn trim_start(s: &str) -> &str {
+    s.trim_start()
+}
+
+#[allow(clippy::needless_lifetimes)]
+pub fn trim_start_explicit<'a>(s: &'a str) -> &'a str {
+    s.trim_start()
+}
+
+// Rule 1 with several inputs: each gets its own lifetime, fine as long as nothing is returned by reference
+
+/// Counts the non-overlapping occurrences of `needle` in `haystack`.
+///
+/// Returning a reference from two inputs is where elision gives up and asks for an annotation:
+///
+/// ```compile_fail
+/// fn longest(x: &str, y: &str) -> &str { // error: missing lifetime specifier
+///     if x.len() > y.len() { x } else { y }
+/// }
+/// ```
+pub fn count_matches(haystack: &str, needle: &str) -> usize {
+    haystack.matches(needle).count()
+}
+
+#[allow(clippy::needless_lifetimes)]
+pub fn count_matches_explicit<'a, 'b>(haystack: &'a str, needle: &'b str) -> usize {
+    haystack.matches(needle).count()
+}
+
+pub struct Text {
+    pub body: String,
+}
+
+impl Text {
+    // Rule 3: the output borrows from self, not from sep
+    pub fn before(&self, sep: &str) -> &str {
+        self.body.split(sep).next().unwrap_or("")
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    pub fn before_explicit<'a, 'b>(&'a self, sep: &'b str) -> &'a str {
+        self.body.split(sep).next().unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::borrowing::shared::calculate_length_ref;
+
+    #[test]
+    fn single_input_without_output_reference() {
+        for s in ["", "hello", "héllo"] {
+            let s = s.to_string();
+            assert_eq!(calculate_length_ref(&s), calculate_length_ref_explicit(&s));
+        }
+    }
+
+    #[test]
+    fn single_input_lifetime_flows_to_the_output() {
+        for s in ["", "  hello", "hello  ", "\tx y"] {
+            assert_eq!(trim_start(s), trim_start_explicit(s));
+            assert_eq!(trim_start(s).as_ptr(), trim_start_explicit(s).as_ptr());
+        }
+    }
+
+    #[test]
+    fn multiple_inputs_get_separate_lifetimes() {
+        for (haystack, needle) in [("a-b-c", "-"), ("aaaa", "aa"), ("", "x"), ("abc", "d")] {
+            assert_eq!(
+                count_matches(haystack, needle),
+                count_matches_explicit(haystack, needle)
+            );
+        }
+    }
+
+    #[test]
+    fn methods_borrow_from_self() {
+        let text = Text {
+            body: String::from("key=value"),
+        };
+        for sep in ["=", "x", ""] {
+            let sep = sep.to_string();
+            assert_eq!(text.before(&sep), text.before_explicit(&sep));
+        }
+        let result = {
+            let sep = String::from("=");
+            text.before(&sep) // sep is dropped here, result only borrows text
+        };
+        assert_eq!(result, "key");
+    }
+}
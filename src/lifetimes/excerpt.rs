@@ -0,0 +1,80 @@
+// Structs Holding References -------------------------------------
+// A struct that stores a reference needs a lifetime parameter: an ImportantExcerpt<'a> can't
+// outlive the text its `part` points into
+
+/// The first sentence of some text, borrowed from it rather than copied.
+///
+/// ```compile_fail
+/// use ownership::lifetimes::excerpt::ImportantExcerpt;
+///
+/// let excerpt;
+/// {
+///     let novel = String::from("Call me Ishmael. Some years ago...");
+///     excerpt = ImportantExcerpt::first_sentence(&novel); // error: `novel` does not live long enough
+/// }
+/// println!("{}", excerpt.part());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    /// Everything before the first `.`, or the whole text when there is none.
+    pub fn first_sentence(text: &'a str) -> ImportantExcerpt<'a> {
+        let part = text.split('.').next().unwrap_or(text);
+        ImportantExcerpt { part }
+    }
+
+    pub fn part(&self) -> &'a str {
+        self.part
+    }
+
+    /// Returns the held slice. With `&self` among the inputs, elision gives the result the
+    /// lifetime of `self`, not of `_msg`, so `_msg` may be a short-lived temporary.
+    pub fn announce(&self, _msg: &str) -> &str {
+        self.part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sentence_of_a_multi_sentence_string() {
+        let text = String::from("Call me Ishmael. Some years ago. Never mind how long.");
+        assert_eq!(
+            ImportantExcerpt::first_sentence(&text).part(),
+            "Call me Ishmael"
+        );
+    }
+
+    #[test]
+    fn text_without_a_period_is_one_sentence() {
+        let text = String::from("no period here");
+        assert_eq!(
+            ImportantExcerpt::first_sentence(&text).part(),
+            "no period here"
+        );
+    }
+
+    #[test]
+    fn part_points_into_the_original_buffer() {
+        let text = String::from("First. Second.");
+        let excerpt = ImportantExcerpt::first_sentence(&text);
+        assert_eq!(excerpt.part().as_ptr(), text.as_ptr());
+        assert_eq!(excerpt.announce("hello").as_ptr(), text.as_ptr());
+    }
+
+    #[test]
+    fn announce_outlives_the_message() {
+        let text = String::from("Keep me. Not me.");
+        let excerpt = ImportantExcerpt::first_sentence(&text);
+        let part = {
+            let msg = String::from("temporary");
+            excerpt.announce(&msg)
+        };
+        assert_eq!(part, "Keep me");
+    }
+}
@@ -0,0 +1,130 @@
+// Lifetimes ----------------------------------------------------
+// A lifetime names how long a reference is valid. `longest<'a>` says: the returned reference lives
+// at most as long as *both* inputs, so the borrow checker won't let it outlive the shorter one
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+pub mod elision;
+pub mod excerpt;
+pub mod static_demo;
+
+/// Returns the longer of two string slices, `a` when they are the same length.
+///
+/// The result borrows from both inputs, so it can't be used after either of them is gone:
+///
+/// ```compile_fail
+/// use ownership::lifetimes::longest;
+///
+/// let string1 = String::from("long string is long");
+/// let result;
+/// {
+///     let string2 = String::from("xyz");
+///     result = longest(string1.as_str(), string2.as_str()); // error: `string2` does not live long enough
+/// }
+/// println!("The longest string is {}", result);
+/// ```
+pub fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if b.len() > a.len() {
+        b
+    } else {
+        a
+    }
+}
+
+/// Same as [`longest`], but returns an owned copy, which carries no lifetime at all.
+pub fn longest_owned(a: &str, b: &str) -> String {
+    longest(a, b).to_string()
+}
+
+/// The valid version of the classic scenario: the result is only used while both inputs live,
+/// and copied into an owned String to leave the inner scope.
+pub fn inner_scope_demo(out: &mut dyn Write) -> io::Result<String> {
+    let string1 = String::from("long string is long");
+    let owned;
+    {
+        let string2 = String::from("xyz");
+        let result = longest(string1.as_str(), string2.as_str());
+        writeln!(out, "inside the scope, the longest string is {}", result)?;
+        owned = longest_owned(string1.as_str(), string2.as_str());
+    } // string2 is dropped here, any reference into it would now be dangling
+    writeln!(out, "outside the scope, the owned copy is {}", owned)?;
+    Ok(owned)
+}
+
+pub struct LifetimesExample;
+
+impl Example for LifetimesExample {
+    fn name(&self) -> &str {
+        "lifetimes"
+    }
+
+    fn description(&self) -> &str {
+        "returned references can't outlive their inputs"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mod.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        inner_scope_demo(out)?;
+
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        let excerpt = excerpt::ImportantExcerpt::first_sentence(&novel);
+        let msg = String::from("the excerpt borrows from the novel");
+        writeln!(out, "Attention please: {}", msg)?;
+        writeln!(out, "excerpt: {}", excerpt.announce(&msg))
+    } // excerpt is dropped before novel, as it must be
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_picks_the_longer_input() {
+        assert_eq!(longest("abc", "abcd"), "abcd");
+        assert_eq!(longest("abcd", "abc"), "abcd");
+    }
+
+    #[test]
+    fn longest_prefers_the_first_on_equal_lengths() {
+        let (a, b) = (String::from("one"), String::from("two"));
+        assert_eq!(longest(&a, &b).as_ptr(), a.as_ptr());
+    }
+
+    #[test]
+    fn longest_of_empty_strings() {
+        assert_eq!(longest("", ""), "");
+        assert_eq!(longest("", "x"), "x");
+        assert_eq!(longest("x", ""), "x");
+    }
+
+    #[test]
+    fn longest_counts_bytes() {
+        assert_eq!(longest("éé", "abc"), "éé"); // 4 bytes against 3
+    }
+
+    #[test]
+    fn longest_owned_outlives_its_inputs() {
+        let owned = {
+            let a = String::from("short");
+            let b = String::from("longer");
+            longest_owned(&a, &b)
+        };
+        assert_eq!(owned, "longer");
+    }
+
+    #[test]
+    fn inner_scope_demo_returns_the_owned_copy() {
+        assert_eq!(
+            inner_scope_demo(&mut Vec::new()).unwrap(),
+            "long string is long"
+        );
+    }
+}
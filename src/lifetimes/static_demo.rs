@@ -0,0 +1,93 @@
+// The 'static Lifetime ---------------------------------------------
+// `&'static str` means "this reference is valid until the program ends", like a string literal
+// hardcoded into the binary. A `T: 'static` bound means something weaker: T holds no borrowed
+// data that could expire, which every owned type satisfies
+use std::fmt::Debug;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// String literals live in the binary, so they are valid for the whole program.
+pub const GREETING: &str = "hello"; // same as &'static str
+
+/// Gives up ownership of `s` for good: the heap buffer is never freed, so it lives for the rest
+/// of the program and can be handed out as `&'static str`.
+pub fn leak_to_static(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+/// Accepts any value that doesn't borrow from something that could be dropped first.
+///
+/// Owned values and string literals qualify, a borrow of a local `String` doesn't:
+///
+/// ```compile_fail
+/// use ownership::lifetimes::static_demo::describe_static;
+///
+/// let local = String::from("hello");
+/// describe_static(&local); // error: `local` does not live long enough
+/// ```
+pub fn describe_static<T: Debug + 'static>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+pub struct StaticExample;
+
+impl Example for StaticExample {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    fn description(&self) -> &str {
+        "'static references and 'static bounds"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("static_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        writeln!(out, "literal: {} (lives in the binary)", GREETING)?;
+
+        let leaked = leak_to_static(String::from("leaked"));
+        writeln!(out, "leaked: {} (heap, never freed)", leaked)?;
+
+        // both satisfy T: 'static, but only the literal lives for the whole program
+        writeln!(
+            out,
+            "owned String: {}",
+            describe_static(String::from("owned"))
+        )?;
+        writeln!(out, "literal:      {}", describe_static(GREETING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaked_str_keeps_the_contents() {
+        let leaked = leak_to_static(String::from("forever"));
+        assert_eq!(leaked, "forever");
+    }
+
+    #[test]
+    fn leaked_strs_can_be_stored_as_static() {
+        let mut names: Vec<&'static str> = vec![GREETING];
+        for i in 0..3 {
+            names.push(leak_to_static(format!("name {}", i)));
+        }
+        assert_eq!(names, ["hello", "name 0", "name 1", "name 2"]);
+    }
+
+    #[test]
+    fn static_bound_accepts_owned_values_and_literals() {
+        assert_eq!(describe_static(String::from("owned")), "\"owned\"");
+        assert_eq!(describe_static("literal"), "\"literal\"");
+        assert_eq!(describe_static(vec![1, 2]), "[1, 2]");
+    }
+}
@@ -0,0 +1,196 @@
+// A Singly Linked Stack ----------------------------------------
+// Each node owns the next one through a Box, so the whole list has a single owner: the head. Moving
+// nodes around behind `&mut self` relies on Option::take, which leaves None behind while we work
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct Stack<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack { head: None, len: 0 }
+    }
+
+    pub fn push(&mut self, value: T) {
+        let next = self.head.take(); // the old head moves into the new node
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    /// Moves the top value out, handing its ownership to the caller.
+    pub fn pop(&mut self) -> Option<T> {
+        match self.head.take() {
+            Some(node) => {
+                let Node { value, next } = *node; // unbox and split the node into its fields
+                self.head = next;
+                self.len -= 1;
+                Some(value)
+            }
+            None => None,
+        }
+    }
+
+    /// Borrows the top value. The stack can't change while the reference is alive:
+    ///
+    /// ```compile_fail
+    /// use ownership::list::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push(1);
+    /// let top = stack.peek();
+    /// stack.push(2); // error: cannot borrow `stack` as mutable because it is also borrowed as immutable
+    /// println!("{:?}", top);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Stack<T> {
+        Stack::new()
+    }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // the default drop would recurse once per node, unlinking one at a time keeps it flat
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        } // node is dropped here, with nothing left behind it
+    }
+}
+
+/// Iterates over the values from top to bottom, borrowing the stack.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+pub struct ListExample;
+
+impl Example for ListExample {
+    fn name(&self) -> &str {
+        "list"
+    }
+
+    fn description(&self) -> &str {
+        "a linked stack where each node owns the next"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("list.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut stack = Stack::new();
+        for word in ["bottom", "middle", "top"] {
+            stack.push(String::from(word));
+        }
+        let values: Vec<&String> = stack.iter().collect();
+        writeln!(out, "iter: {:?}", values)?;
+        writeln!(out, "peek: {:?}", stack.peek())?;
+        if let Some(top) = stack.peek_mut() {
+            top.push('!');
+        }
+        let popped = stack.pop(); // the String moves out of the stack
+        writeln!(out, "pop: {:?}, {} left", popped, stack.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_returns_values_in_reverse_push_order() {
+        let mut stack = Stack::new();
+        assert_eq!(stack.pop(), None);
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn peek_and_peek_mut() {
+        let mut stack = Stack::new();
+        assert_eq!(stack.peek(), None);
+        stack.push(String::from("a"));
+        if let Some(top) = stack.peek_mut() {
+            top.push_str("bc");
+        }
+        assert_eq!(stack.peek().map(String::as_str), Some("abc"));
+        assert_eq!(stack.pop().as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn iter_goes_top_to_bottom() {
+        let mut stack = Stack::new();
+        for i in 0..5 {
+            stack.push(i);
+        }
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+        assert_eq!(stack.len(), 5); // iterating only borrowed
+    }
+
+    #[test]
+    fn dropping_a_long_list_does_not_overflow_the_stack() {
+        let mut stack = Stack::new();
+        for i in 0..100_000 {
+            stack.push(i);
+        }
+        drop(stack);
+    }
+}
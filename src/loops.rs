@@ -0,0 +1,133 @@
+// For Loops and Ownership --------------------------------------
+// `for s in v` calls `v.into_iter()` and moves every String out, `for s in &v` iterates `&String`s
+// and `for s in &mut v` iterates `&mut String`s. Only the first one uses up the Vec
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Adds up the byte lengths, the loop only borrows the Strings.
+pub fn total_len_by_ref(v: &Vec<String>) -> usize {
+    let mut total = 0;
+    for s in v {
+        total += s.len();
+    }
+    total
+}
+
+/// Joins the Strings with spaces, moving each one out of `v` as the loop reaches it.
+///
+/// The loop consumes the Vec, so it can't be used after it:
+///
+/// ```compile_fail
+/// let v = vec![String::from("a"), String::from("b")];
+/// for s in v {
+///     println!("{}", s);
+/// }
+/// println!("{}", v.len()); // error: borrow of moved value: `v`
+/// ```
+pub fn consume_and_join(v: Vec<String>) -> String {
+    let mut joined = String::new();
+    for s in v {
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(&s);
+    } // s is dropped at the end of every iteration, v's buffer after the last one
+    joined
+}
+
+/// Trims the whitespace around every String in place, the loop lending out each one mutably.
+pub fn trim_all(v: &mut Vec<String>) {
+    for s in v {
+        let end = s.trim_end().len();
+        s.truncate(end);
+        let start = s.len() - s.trim_start().len();
+        s.drain(..start);
+    }
+}
+
+pub struct LoopsExample;
+
+impl Example for LoopsExample {
+    fn name(&self) -> &str {
+        "loops"
+    }
+
+    fn description(&self) -> &str {
+        "for over v, &v and &mut v"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("loops.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut v = vec![
+            String::from("  one "),
+            String::from("owner\t"),
+            String::from(" at a time"),
+        ];
+        writeln!(
+            out,
+            "for s in &v: total_len_by_ref = {}, v is alive: {:?}",
+            total_len_by_ref(&v),
+            v
+        )?;
+        trim_all(&mut v);
+        writeln!(out, "for s in &mut v: trim_all, v is alive: {:?}", v)?;
+        let joined = consume_and_join(v);
+        writeln!(
+            out,
+            "for s in v: consume_and_join = {:?}, v was moved into the loop",
+            joined
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::strings;
+
+    #[test]
+    fn total_len_by_ref_leaves_the_vec() {
+        let v = strings(&["ab", "", "héllo"]);
+        assert_eq!(total_len_by_ref(&v), 8);
+        assert_eq!(v.len(), 3);
+        assert_eq!(total_len_by_ref(&Vec::new()), 0);
+    }
+
+    #[test]
+    fn consume_and_join_uses_spaces() {
+        assert_eq!(consume_and_join(strings(&["a", "b", "c"])), "a b c");
+        assert_eq!(consume_and_join(strings(&["only"])), "only");
+        assert_eq!(consume_and_join(Vec::new()), "");
+    }
+
+    #[test]
+    fn trim_all_in_place() {
+        let mut v = strings(&["  a ", "b", "\tc d\n"]);
+        trim_all(&mut v);
+        assert_eq!(v, ["a", "b", "c d"]);
+    }
+
+    #[test]
+    fn trim_all_whitespace_only_strings() {
+        let mut v = strings(&["   ", "\t\n", ""]);
+        trim_all(&mut v);
+        assert_eq!(v, ["", "", ""]);
+    }
+
+    #[test]
+    fn trim_all_keeps_the_buffers() {
+        let mut v = strings(&["  keep me  "]);
+        let ptr = v[0].as_ptr();
+        trim_all(&mut v);
+        assert_eq!(v[0], "keep me");
+        assert_eq!(v[0].as_ptr(), ptr);
+    }
+}
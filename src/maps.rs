@@ -0,0 +1,116 @@
+// HashMap and Ownership ----------------------------------------
+// Inserting into a HashMap moves owned keys and values into the map, get only lends a reference back
+// and the entry API updates a value in place without a second lookup or a clone
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Counts words case-insensitively, ignoring punctuation around them.
+///
+/// The map owns its keys, so a `String` key is moved in and can't be used afterwards:
+///
+/// ```compile_fail
+/// use std::collections::HashMap;
+///
+/// let key = String::from("color");
+/// let value = String::from("blue");
+/// let mut map = HashMap::new();
+/// map.insert(key, value);
+/// println!("{} = {}", key, value); // error: borrow of moved value: `key`
+/// ```
+pub fn count_words(text: &str) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() {
+            continue;
+        }
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1; // one lookup, no clone of the key
+    }
+    counts
+}
+
+/// Looks `key` up, falling back to `""`. The result borrows from the map, not from `key`.
+pub fn get_or_default<'a>(map: &'a HashMap<String, String>, key: &str) -> &'a str {
+    map.get(key).map(String::as_str).unwrap_or("")
+}
+
+pub struct MapsExample;
+
+impl Example for MapsExample {
+    fn name(&self) -> &str {
+        "maps"
+    }
+
+    fn description(&self) -> &str {
+        "HashMap insert, get and the entry API"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("maps.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut colors = HashMap::new();
+        let key = String::from("sky");
+        let value = String::from("blue");
+        colors.insert(key, value); // key and value are moved into the map
+        writeln!(out, "get(sky) = {:?}", colors.get("sky"))?;
+        writeln!(
+            out,
+            "get_or_default(grass) = {:?}",
+            get_or_default(&colors, "grass")
+        )?;
+
+        let counts = count_words("the cat and the hat, the end.");
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort();
+        writeln!(out, "count_words: {:?}", counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_words_counts_repeats() {
+        let counts = count_words("a b a c a b");
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 2);
+        assert_eq!(counts["c"], 1);
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn count_words_ignores_punctuation_and_case() {
+        let counts = count_words("Hello, hello! (HELLO) -- world.");
+        assert_eq!(counts["hello"], 3);
+        assert_eq!(counts["world"], 1);
+        assert_eq!(counts.len(), 2);
+    }
+
+    #[test]
+    fn count_words_on_empty_input() {
+        assert!(count_words("").is_empty());
+        assert!(count_words("  ... !! ").is_empty());
+    }
+
+    #[test]
+    fn get_or_default_borrows_from_the_map() {
+        let mut map = HashMap::new();
+        map.insert(String::from("k"), String::from("v"));
+        let found = {
+            let key = String::from("k");
+            get_or_default(&map, &key)
+        }; // key is gone, the result still borrows the map
+        assert_eq!(found, "v");
+        assert_eq!(found.as_ptr(), map["k"].as_ptr());
+        assert_eq!(get_or_default(&map, "missing"), "");
+    }
+}
@@ -0,0 +1,124 @@
+// mem::swap, mem::replace and mem::take -----------------------------
+// The general versions of Option::take: they move a value out of a `&mut` location by putting
+// another value in its place in the same step, so the location is never left empty
+use std::io::{self, Write};
+use std::mem;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Swaps the contents of two Strings through mutable references; only the pointers move.
+pub fn swap_strings(a: &mut String, b: &mut String) {
+    mem::swap(a, b);
+}
+
+#[derive(Debug, Default)]
+pub struct Buffer {
+    pub data: String,
+}
+
+impl Buffer {
+    pub fn write(&mut self, s: &str) {
+        self.data.push_str(s);
+    }
+
+    /// Hands out everything written so far, leaving an empty String (its `Default`) behind.
+    ///
+    /// Moving the field out directly would leave the Buffer without its data:
+    ///
+    /// ```compile_fail
+    /// struct Buffer {
+    ///     data: String,
+    /// }
+    ///
+    /// impl Buffer {
+    ///     fn flush(&mut self) -> String {
+    ///         self.data // error: cannot move out of `self.data` which is behind a mutable reference
+    ///     }
+    /// }
+    /// ```
+    pub fn flush(&mut self) -> String {
+        mem::take(&mut self.data)
+    }
+
+    /// Like [`Buffer::flush`], but leaves `placeholder` behind instead of an empty String.
+    pub fn flush_with(&mut self, placeholder: &str) -> String {
+        mem::replace(&mut self.data, placeholder.to_string())
+    }
+}
+
+pub struct MemTricksExample;
+
+impl Example for MemTricksExample {
+    fn name(&self) -> &str {
+        "mem-tricks"
+    }
+
+    fn description(&self) -> &str {
+        "mem::swap, replace and take move values out of &mut"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mem_tricks.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut a = String::from("left");
+        let mut b = String::from("right");
+        swap_strings(&mut a, &mut b);
+        writeln!(out, "swap:    a = {}, b = {}", a, b)?;
+
+        let mut buffer = Buffer::default();
+        buffer.write("first line");
+        let old = buffer.flush_with("placeholder");
+        writeln!(out, "replace: got {:?}, left {:?}", old, buffer.data)?;
+        let taken = buffer.flush();
+        writeln!(out, "take:    got {:?}, left {:?}", taken, buffer.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_exchanges_the_heap_buffers() {
+        let mut a = String::from("left");
+        let mut b = String::from("right");
+        let (pa, pb) = (a.as_ptr(), b.as_ptr());
+        swap_strings(&mut a, &mut b);
+        assert_eq!((a.as_str(), b.as_str()), ("right", "left"));
+        assert_eq!((a.as_ptr(), b.as_ptr()), (pb, pa));
+    }
+
+    #[test]
+    fn flush_takes_the_data_and_leaves_it_empty() {
+        let mut buffer = Buffer::default();
+        buffer.write("abc");
+        buffer.write("def");
+        assert_eq!(buffer.flush(), "abcdef");
+        assert_eq!(buffer.data, "");
+        assert_eq!(buffer.flush(), "");
+    }
+
+    #[test]
+    fn flush_with_leaves_the_placeholder() {
+        let mut buffer = Buffer::default();
+        buffer.write("abc");
+        assert_eq!(buffer.flush_with("-"), "abc");
+        assert_eq!(buffer.data, "-");
+    }
+
+    #[test]
+    fn take_works_on_any_default_type() {
+        let mut v = vec![1, 2, 3];
+        assert_eq!(mem::take(&mut v), [1, 2, 3]);
+        assert!(v.is_empty());
+        let mut n = 7;
+        assert_eq!(mem::take(&mut n), 7);
+        assert_eq!(n, 0);
+    }
+}
@@ -0,0 +1,142 @@
+// A Smart Pointer of Our Own -----------------------------------
+// MyBox owns the value it wraps and implements Deref and DerefMut, so `&MyBox<String>` coerces to
+// `&String` and then to `&str` wherever one is expected, exactly like Box does
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use crate::borrowing::shared::calculate_length_str;
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, DropLog};
+
+/// Owns a `T` and logs `drop: MyBox` when it is dropped while still holding it.
+///
+/// The value sits in an `Option` so `into_inner` can move it out despite the Drop impl (a type
+/// with Drop can't be taken apart by a move). `ManuallyDrop` would avoid the `None` state, but
+/// needs unsafe code to read the value out, the Option costs a branch and needs none.
+pub struct MyBox<T> {
+    value: Option<T>,
+    log: DropLog,
+}
+
+impl<T> MyBox<T> {
+    pub fn new(value: T, log: &DropLog) -> MyBox<T> {
+        MyBox {
+            value: Some(value),
+            log: Rc::clone(log),
+        }
+    }
+
+    /// Moves the value back out. The box is left empty, so its Drop has nothing to log.
+    pub fn into_inner(mut self) -> T {
+        self.value
+            .take()
+            .expect("only into_inner empties the box, and it consumes it")
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+            .as_ref()
+            .expect("only into_inner empties the box, and it consumes it")
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+            .as_mut()
+            .expect("only into_inner empties the box, and it consumes it")
+    }
+}
+
+impl<T> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        if self.value.is_some() {
+            self.log.borrow_mut().push(String::from("drop: MyBox"));
+        }
+    }
+}
+
+pub struct MyBoxExample;
+
+impl Example for MyBoxExample {
+    fn name(&self) -> &str {
+        "mybox"
+    }
+
+    fn description(&self) -> &str {
+        "a custom smart pointer with Deref and Drop"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mybox.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let log = new_log();
+        {
+            let mut m = MyBox::new(String::from("Rust"), &log);
+            // &MyBox<String> -> &String -> &str, two deref coercions
+            writeln!(
+                out,
+                "calculate_length_str(&m) = {}",
+                calculate_length_str(&m)
+            )?;
+            m.push_str("acean"); // DerefMut reaches String::push_str
+            writeln!(out, "after push_str through DerefMut: {}", *m)?;
+        } // m goes out of scope
+        let inner = MyBox::new(String::from("moved out"), &log).into_inner();
+        writeln!(out, "into_inner: {:?}", inner)?;
+        let events = log.borrow();
+        writeln!(out, "drop log: {:?}", events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deref_coerces_to_str() {
+        let log = new_log();
+        let m = MyBox::new(String::from("hello"), &log);
+        assert_eq!(calculate_length_str(&m), 5);
+        assert_eq!(*m, "hello");
+    }
+
+    #[test]
+    fn deref_mut_changes_the_inner_value() {
+        let log = new_log();
+        let mut m = MyBox::new(vec![1, 2], &log);
+        m.push(3);
+        assert_eq!(*m, [1, 2, 3]);
+    }
+
+    #[test]
+    fn drop_fires_exactly_once() {
+        let log = new_log();
+        let m = MyBox::new(5, &log);
+        assert!(log.borrow().is_empty());
+        drop(m);
+        assert_eq!(*log.borrow(), ["drop: MyBox"]);
+    }
+
+    #[test]
+    fn into_inner_suppresses_the_drop_and_keeps_the_value() {
+        let log = new_log();
+        let s = String::from("inner");
+        let ptr = s.as_ptr();
+        let inner = MyBox::new(s, &log).into_inner();
+        assert_eq!(inner, "inner");
+        assert_eq!(inner.as_ptr(), ptr);
+        assert!(log.borrow().is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+// Newtypes -----------------------------------------------------
+// A tuple struct around a single value gives it a new type without any runtime cost. The wrapper
+// owns the inner value: wrapping moves it in, AsRef and Deref lend it out, into_inner moves it back
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::{Add, Deref};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// A distance that can't be mixed up with any other f64. It is Copy, so wrapping copies.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ValidationError {
+    Empty,
+    TooLong(usize),
+    InvalidChar(char),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Empty => write!(f, "a username can't be empty"),
+            ValidationError::TooLong(len) => {
+                write!(
+                    f,
+                    "a username is at most {} chars, got {}",
+                    Username::MAX_LEN,
+                    len
+                )
+            }
+            ValidationError::InvalidChar(c) => write!(f, "{:?} is not allowed in a username", c),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A String that owns its text and, when built with `new`, has been checked.
+///
+/// Wrapping moves the String in, so the old binding can't be used afterwards:
+///
+/// ```compile_fail
+/// use ownership::newtype::Username;
+///
+/// let name = String::from("ferris");
+/// let user = Username::from(name);
+/// println!("{}", name); // error: borrow of moved value: `name`
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Username(String);
+
+impl Username {
+    pub const MAX_LEN: usize = 16;
+
+    /// Validates and wraps `s`. On failure the String is handed back, so the caller doesn't lose it.
+    pub fn new(s: String) -> Result<Username, (String, ValidationError)> {
+        let len = s.chars().count();
+        if len == 0 {
+            return Err((s, ValidationError::Empty));
+        }
+        if len > Username::MAX_LEN {
+            return Err((s, ValidationError::TooLong(len)));
+        }
+        if let Some(c) = s.chars().find(|c| !(c.is_alphanumeric() || *c == '_')) {
+            return Err((s, ValidationError::InvalidChar(c)));
+        }
+        Ok(Username(s))
+    }
+
+    /// Unwraps the String, moving it back out without a copy.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+/// Wraps without validating, for text that is already known to be a valid username.
+impl From<String> for Username {
+    fn from(s: String) -> Username {
+        Username(s)
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Username {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn greet(name: &str) -> String {
+    format!("hello, {}", name)
+}
+
+pub struct NewtypeExample;
+
+impl Example for NewtypeExample {
+    fn name(&self) -> &str {
+        "newtype"
+    }
+
+    fn description(&self) -> &str {
+        "wrappers that own their inner value"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("newtype.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let run = Meters(5.0);
+        let total = run + Meters(2.5); // Meters is Copy, run is still usable
+        writeln!(out, "{:?} + 2.5 = {:?}", run, total)?;
+
+        let user = Username::new(String::from("ferris")).expect("a valid name");
+        writeln!(out, "Deref to &str: {}, len {}", greet(&user), user.len())?;
+        let inner = user.into_inner(); // the String moves back out
+        writeln!(out, "into_inner: {:?}", inner)?;
+
+        match Username::new(String::from("not valid!")) {
+            Ok(user) => writeln!(out, "unexpected: {:?}", user),
+            Err((input, err)) => writeln!(out, "rejected {:?}: {}, input handed back", input, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_names_are_wrapped_without_copying() {
+        let s = String::from("ferris_42");
+        let ptr = s.as_ptr();
+        let user = Username::new(s).unwrap();
+        assert_eq!(user.as_ref(), "ferris_42");
+        let inner = user.into_inner();
+        assert_eq!(inner.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn failure_hands_the_input_back() {
+        let s = String::from("bad name");
+        let ptr = s.as_ptr();
+        let (back, err) = Username::new(s).unwrap_err();
+        assert_eq!(err, ValidationError::InvalidChar(' '));
+        assert_eq!(back, "bad name");
+        assert_eq!(back.as_ptr(), ptr); // the very same String
+
+        assert_eq!(
+            Username::new(String::new()).unwrap_err().1,
+            ValidationError::Empty
+        );
+        let long = "x".repeat(17);
+        assert_eq!(
+            Username::new(long).unwrap_err().1,
+            ValidationError::TooLong(17)
+        );
+    }
+
+    #[test]
+    fn deref_lets_a_username_stand_in_for_str() {
+        let user = Username::from(String::from("ferris"));
+        assert_eq!(greet(&user), "hello, ferris");
+        assert!(user.starts_with("fer")); // str methods through Deref
+    }
+
+    #[test]
+    fn meters_are_copied() {
+        let a = Meters(1.5);
+        let b = a + a;
+        assert_eq!(b, Meters(3.0));
+        assert_eq!(a, Meters(1.5));
+    }
+}
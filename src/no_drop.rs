@@ -0,0 +1,137 @@
+// Skipping Drop: mem::forget and ManuallyDrop ------------------
+// Rule 3 says a value is dropped when its owner goes out of scope, but the owner can opt out.
+// `mem::forget` takes ownership and never runs the destructor, `ManuallyDrop` wraps a value whose
+// destructor only runs when asked. Both are safe: Rust never promised that destructors run, a leak
+// wastes memory but can't cause use-after-free or a double free
+use std::io::{self, Write};
+use std::mem::{self, ManuallyDrop};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{mark, new_log, DropLog, Tracer};
+
+/// Forgets a Tracer: it is never dropped, so its "drop" event never shows up.
+pub fn forget(log: &DropLog) {
+    let t = Tracer::new("forgotten", log);
+    mem::forget(t); // the heap memory behind the Tracer leaks
+    mark(log, "end of scope");
+}
+
+/// Wraps a Tracer in ManuallyDrop and drops it explicitly, exactly once.
+pub fn manually_drop(log: &DropLog) {
+    let mut t = ManuallyDrop::new(Tracer::new("manual", log));
+    mark(log, &format!("still usable: {}", t.label()));
+    // SAFETY: `t` is not used again after this, so it is neither read nor dropped a second time
+    unsafe { ManuallyDrop::drop(&mut t) };
+    mark(log, "end of scope");
+} // ManuallyDrop itself has no destructor, nothing more is logged
+
+/// Rolls back when dropped, unless it has been committed.
+///
+/// It only borrows what it needs, so forgetting it in `commit` leaks nothing.
+pub struct Transaction<'a> {
+    name: &'a str,
+    log: &'a DropLog,
+}
+
+impl<'a> Transaction<'a> {
+    pub fn begin(name: &'a str, log: &'a DropLog) -> Transaction<'a> {
+        mark(log, &format!("begin: {}", name));
+        Transaction { name, log }
+    }
+
+    /// Consumes the guard and skips its Drop, so no rollback happens.
+    pub fn commit(self) {
+        mark(self.log, &format!("commit: {}", self.name));
+        mem::forget(self);
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        mark(self.log, &format!("rollback: {}", self.name));
+    }
+}
+
+type Demo = fn(&DropLog);
+
+fn transactions(log: &DropLog) {
+    Transaction::begin("committed", log).commit();
+    {
+        let _tx = Transaction::begin("abandoned", log);
+    } // dropped without commit
+}
+
+pub struct NoDropExample;
+
+impl Example for NoDropExample {
+    fn name(&self) -> &str {
+        "no-drop"
+    }
+
+    fn description(&self) -> &str {
+        "mem::forget and ManuallyDrop skip the destructor"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("no_drop.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let demos: [(&str, Demo); 3] = [
+            ("mem::forget", forget),
+            ("ManuallyDrop", manually_drop),
+            ("transaction guard", transactions),
+        ];
+        for (title, demo) in demos {
+            let log = new_log();
+            demo(&log);
+            writeln!(out, "{}:", title)?;
+            for event in log.borrow().iter() {
+                writeln!(out, "  {}", event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracer::events;
+
+    #[test]
+    fn forgotten_value_never_drops() {
+        assert_eq!(events(forget), ["new: forgotten", "end of scope"]);
+    }
+
+    #[test]
+    fn manually_dropped_value_drops_exactly_once() {
+        assert_eq!(
+            events(manually_drop),
+            [
+                "new: manual",
+                "still usable: manual",
+                "drop: manual",
+                "end of scope"
+            ]
+        );
+    }
+
+    #[test]
+    fn committed_transaction_does_not_roll_back() {
+        let log = new_log();
+        Transaction::begin("tx", &log).commit();
+        assert_eq!(*log.borrow(), ["begin: tx", "commit: tx"]);
+    }
+
+    #[test]
+    fn dropped_transaction_rolls_back() {
+        let log = new_log();
+        drop(Transaction::begin("tx", &log));
+        assert_eq!(*log.borrow(), ["begin: tx", "rollback: tx"]);
+    }
+}
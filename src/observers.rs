@@ -0,0 +1,171 @@
+// Observers with Weak Subscribers ------------------------------
+// An event bus that held Rc handles would keep every subscriber alive for as long as the bus lives.
+// Holding Weak handles leaves ownership with whoever created the subscriber: once they drop it,
+// the bus notices on the next publish and forgets it
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::{Rc, Weak};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, DropLog, Tracer};
+
+pub trait Subscriber {
+    fn notify(&mut self, event: &str);
+}
+
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Weak<RefCell<dyn Subscriber>>>,
+}
+
+impl EventBus {
+    pub fn new() -> EventBus {
+        EventBus::default()
+    }
+
+    /// Registers `s` without taking ownership of it.
+    pub fn subscribe(&mut self, s: &Rc<RefCell<dyn Subscriber>>) {
+        self.subscribers.push(Rc::downgrade(s));
+    }
+
+    /// Notifies every live subscriber and prunes the ones that have been dropped.
+    pub fn publish(&mut self, event: &str) {
+        self.subscribers.retain(|weak| match weak.upgrade() {
+            Some(subscriber) => {
+                subscriber.borrow_mut().notify(event);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Number of subscribers the bus still knows about, dropped ones included until the next publish.
+    pub fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+}
+
+/// A subscriber that records every event it receives into a shared log.
+pub struct Recorder {
+    name: String,
+    log: DropLog,
+    _tracer: Tracer,
+}
+
+impl Recorder {
+    /// Creates a recorder, already behind the `Rc<RefCell<dyn Subscriber>>` the bus expects.
+    pub fn subscriber(name: &str, log: &DropLog) -> Rc<RefCell<dyn Subscriber>> {
+        Rc::new(RefCell::new(Recorder {
+            name: name.to_string(),
+            log: Rc::clone(log),
+            _tracer: Tracer::new(name, log),
+        }))
+    }
+}
+
+impl Subscriber for Recorder {
+    fn notify(&mut self, event: &str) {
+        self.log
+            .borrow_mut()
+            .push(format!("{} got {}", self.name, event));
+    }
+}
+
+pub struct ObserversExample;
+
+impl Example for ObserversExample {
+    fn name(&self) -> &str {
+        "observers"
+    }
+
+    fn description(&self) -> &str {
+        "an event bus holding Weak subscribers"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("observers.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let log = new_log();
+        let mut bus = EventBus::new();
+        let alice = Recorder::subscriber("alice", &log);
+        let bob = Recorder::subscriber("bob", &log);
+        bus.subscribe(&alice);
+        bus.subscribe(&bob);
+
+        bus.publish("first");
+        writeln!(out, "after first: {} subscribers", bus.len())?;
+        drop(bob); // the only strong handle, so bob is freed even though the bus knows him
+        writeln!(out, "bob dropped: {} subscribers", bus.len())?;
+        bus.publish("second");
+        writeln!(out, "after second: {} subscribers", bus.len())?;
+        drop(alice);
+
+        let events = log.borrow();
+        writeln!(out, "{}", events.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(log: &DropLog, prefix: &str) -> usize {
+        log.borrow()
+            .iter()
+            .filter(|e| e.starts_with(prefix))
+            .count()
+    }
+
+    #[test]
+    fn live_subscribers_are_notified() {
+        let log = new_log();
+        let mut bus = EventBus::new();
+        let a = Recorder::subscriber("a", &log);
+        let b = Recorder::subscriber("b", &log);
+        bus.subscribe(&a);
+        bus.subscribe(&b);
+        bus.publish("one");
+        bus.publish("two");
+        assert_eq!(count(&log, "a got"), 2);
+        assert_eq!(count(&log, "b got"), 2);
+    }
+
+    #[test]
+    fn dropped_subscribers_are_pruned() {
+        let log = new_log();
+        let mut bus = EventBus::new();
+        let a = Recorder::subscriber("a", &log);
+        let b = Recorder::subscriber("b", &log);
+        bus.subscribe(&a);
+        bus.subscribe(&b);
+        drop(a);
+        assert_eq!(bus.len(), 2); // not noticed until the next publish
+        bus.publish("event");
+        assert_eq!(bus.len(), 1);
+        assert_eq!(count(&log, "a got"), 0);
+        assert_eq!(count(&log, "b got"), 1);
+    }
+
+    #[test]
+    fn the_bus_does_not_keep_subscribers_alive() {
+        let log = new_log();
+        let mut bus = EventBus::new();
+        let a = Recorder::subscriber("a", &log);
+        bus.subscribe(&a);
+        assert_eq!(Rc::strong_count(&a), 1);
+        drop(a);
+        assert_eq!(count(&log, "drop: a"), 1); // freed while the bus still exists
+        bus.publish("event");
+        assert!(bus.is_empty());
+    }
+}
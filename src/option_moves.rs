@@ -0,0 +1,100 @@
+// Moving Out of an Option Behind &mut --------------------------------
+// A `&mut` lets you change a value but not take it: the owner expects something to still be there.
+// `Option::take` moves the value out and leaves `None` behind, `Option::replace` leaves a new one
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Slot {
+    pub value: Option<String>,
+}
+
+impl Slot {
+    pub fn new(value: &str) -> Slot {
+        Slot {
+            value: Some(value.to_string()),
+        }
+    }
+
+    /// Moves the value out, leaving the slot empty.
+    ///
+    /// Moving the field out directly would leave `self` without a value:
+    ///
+    /// ```compile_fail
+    /// struct Slot {
+    ///     value: Option<String>,
+    /// }
+    ///
+    /// impl Slot {
+    ///     fn steal(&mut self) -> Option<String> {
+    ///         self.value // error: cannot move out of `self.value` which is behind a mutable reference
+    ///     }
+    /// }
+    /// ```
+    pub fn steal(&mut self) -> Option<String> {
+        self.value.take()
+    }
+
+    /// Puts `value` in the slot and returns whatever was there before.
+    pub fn swap_in(&mut self, value: &str) -> Option<String> {
+        self.value.replace(value.to_string())
+    }
+}
+
+pub struct OptionMovesExample;
+
+impl Example for OptionMovesExample {
+    fn name(&self) -> &str {
+        "option-moves"
+    }
+
+    fn description(&self) -> &str {
+        "take and replace move values out from behind &mut"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("option_moves.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut slot = Slot::new("gold");
+        let stolen = slot.steal();
+        writeln!(out, "steal: got {:?}, slot is now {:?}", stolen, slot.value)?;
+        let again = slot.steal();
+        writeln!(out, "steal again: got {:?}", again)?;
+        let old = slot.swap_in("silver");
+        writeln!(out, "swap_in: got {:?}, slot is now {:?}", old, slot.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stealing_from_a_full_slot_empties_it() {
+        let mut slot = Slot::new("gold");
+        assert_eq!(slot.steal().as_deref(), Some("gold"));
+        assert_eq!(slot.value, None);
+    }
+
+    #[test]
+    fn stealing_from_an_empty_slot_gives_none() {
+        let mut slot = Slot::default();
+        assert_eq!(slot.steal(), None);
+        assert_eq!(slot.value, None);
+    }
+
+    #[test]
+    fn swap_in_returns_the_old_value() {
+        let mut slot = Slot::new("gold");
+        assert_eq!(slot.swap_in("silver").as_deref(), Some("gold"));
+        assert_eq!(slot.value.as_deref(), Some("silver"));
+        assert_eq!(Slot::default().swap_in("x"), None);
+    }
+}
@@ -0,0 +1,134 @@
+// Out-Parameters vs Return Values ------------------------------
+// Returning a fresh value is the default and the easiest to read. Writing into a `&mut` the caller
+// owns is worth it when the same buffers are filled over and over: the caller keeps the allocation
+// between calls instead of building and dropping a new one each time
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Summary {
+    pub longest: String,
+    pub words: Vec<String>,
+    pub line_count: usize,
+    pub word_count: usize,
+}
+
+/// Summarizes `lines` into a new Summary, allocating fresh buffers for every call.
+pub fn summarize_return(lines: &[String]) -> Summary {
+    let mut summary = Summary::default();
+    summarize_into(lines, &mut summary);
+    summary
+}
+
+/// Summarizes `lines` into `out`, clearing and refilling its buffers instead of replacing them.
+pub fn summarize_into(lines: &[String], out: &mut Summary) {
+    out.longest.clear(); // clear keeps the capacity
+    out.words.clear();
+    out.line_count = lines.len();
+    out.word_count = 0;
+    for line in lines {
+        if line.len() > out.longest.len() {
+            out.longest.clear();
+            out.longest.push_str(line);
+        }
+        for word in line.split_whitespace() {
+            out.word_count += 1;
+            if !out.words.iter().any(|w| w == word) {
+                out.words.push(word.to_string());
+            }
+        }
+    }
+}
+
+pub struct OutParamsExample;
+
+impl Example for OutParamsExample {
+    fn name(&self) -> &str {
+        "out-params"
+    }
+
+    fn description(&self) -> &str {
+        "returning a value vs filling a &mut out-parameter"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("out_params.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let batches: [&[&str]; 3] = [
+            &["the quick brown fox", "jumps over the lazy dog"],
+            &["hello world"],
+            &["one two", "three"],
+        ];
+        let mut summary = Summary::default();
+        for batch in batches {
+            let lines: Vec<String> = batch.iter().map(|l| l.to_string()).collect();
+            let fresh = summarize_return(&lines);
+            summarize_into(&lines, &mut summary);
+            writeln!(
+                out,
+                "{} words | returned: longest cap {:>2}, words cap {} | reused: longest cap {:>2}, words cap {}",
+                summary.word_count,
+                fresh.longest.capacity(),
+                fresh.words.capacity(),
+                summary.longest.capacity(),
+                summary.words.capacity()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::strings;
+
+    #[test]
+    fn both_styles_agree() {
+        let input = strings(&["a b a", "longer line c"]);
+        let mut into = Summary::default();
+        summarize_into(&input, &mut into);
+        let returned = summarize_return(&input);
+        assert_eq!(into, returned);
+        assert_eq!(returned.longest, "longer line c");
+        assert_eq!(returned.words, ["a", "b", "longer", "line", "c"]);
+        assert_eq!((returned.line_count, returned.word_count), (2, 6));
+    }
+
+    #[test]
+    fn out_param_overwrites_previous_contents() {
+        let mut summary = Summary::default();
+        summarize_into(&strings(&["many words in this first line"]), &mut summary);
+        summarize_into(&strings(&["short"]), &mut summary);
+        assert_eq!(summary, summarize_return(&strings(&["short"])));
+        summarize_into(&[], &mut summary);
+        assert_eq!(summary, Summary::default());
+    }
+
+    #[test]
+    fn out_param_reuses_its_buffers() {
+        let mut summary = Summary::default();
+        summarize_into(
+            &strings(&["the first and the longest line", "more words"]),
+            &mut summary,
+        );
+        let buffers = (summary.longest.as_ptr(), summary.words.as_ptr());
+        let capacities = (summary.longest.capacity(), summary.words.capacity());
+
+        for batch in [&["fits"][..], &["also fits", "yes"], &["the first"]] {
+            summarize_into(&strings(batch), &mut summary);
+            assert_eq!((summary.longest.as_ptr(), summary.words.as_ptr()), buffers);
+            assert_eq!(
+                (summary.longest.capacity(), summary.words.capacity()),
+                capacities
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Output Styling -----------------------------------------------
+// A few ANSI escape codes for banners and annotations. Every function is a plain string
+// transformation, the terminal detection happens once, in `Style::detect`
+use std::env;
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+
+/// The value of `--color=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` isn't set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!(
+                "invalid color `{}`, expected auto, always or never",
+                s
+            )),
+        }
+    }
+}
+
+impl ColorChoice {
+    /// Whether to color, given what `auto` would look at. An explicit `always` beats `NO_COLOR`.
+    pub fn enabled(self, is_terminal: bool, no_color: bool) -> bool {
+        match self {
+            ColorChoice::Auto => is_terminal && !no_color,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// Applies styles, or doesn't, depending on how it was created. The default is [`Style::PLAIN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    color: bool,
+}
+
+impl Style {
+    /// Never colors, what tests and redirected output get.
+    pub const PLAIN: Style = Style { color: false };
+
+    pub fn new(color: bool) -> Style {
+        Style { color }
+    }
+
+    /// Resolves `choice` against the real stdout and environment.
+    pub fn detect(choice: ColorChoice) -> Style {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Style::new(choice.enabled(io::stdout().is_terminal(), no_color))
+    }
+
+    pub fn is_colored(&self) -> bool {
+        self.color
+    }
+
+    /// Example banners.
+    pub fn heading(&self, text: &str) -> String {
+        self.paint(BOLD, text)
+    }
+
+    /// A binding that has been moved out of and can't be used any more.
+    pub fn moved(&self, text: &str) -> String {
+        self.paint(RED, text)
+    }
+
+    /// A binding that still owns its value.
+    pub fn live(&self, text: &str) -> String {
+        self.paint(GREEN, text)
+    }
+
+    /// Secondary information such as timings.
+    pub fn dim(&self, text: &str) -> String {
+        self.paint(DIM, text)
+    }
+
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", code, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all(style: Style) -> [String; 4] {
+        [
+            style.heading("h"),
+            style.moved("m"),
+            style.live("l"),
+            style.dim("d"),
+        ]
+    }
+
+    #[test]
+    fn never_produces_no_escape_sequences() {
+        let style = Style::new(ColorChoice::Never.enabled(true, false));
+        assert_eq!(all(style), ["h", "m", "l", "d"]);
+        assert!(all(Style::PLAIN).iter().all(|s| !s.contains('\x1b')));
+    }
+
+    #[test]
+    fn always_produces_the_expected_codes() {
+        let style = Style::new(ColorChoice::Always.enabled(false, true));
+        assert_eq!(
+            all(style),
+            [
+                "\x1b[1mh\x1b[0m",
+                "\x1b[31mm\x1b[0m",
+                "\x1b[32ml\x1b[0m",
+                "\x1b[2md\x1b[0m"
+            ]
+        );
+    }
+
+    #[test]
+    fn auto_consults_is_terminal_and_no_color() {
+        assert!(ColorChoice::Auto.enabled(true, false));
+        assert!(!ColorChoice::Auto.enabled(false, false));
+        assert!(!ColorChoice::Auto.enabled(true, true));
+    }
+
+    #[test]
+    fn parses_the_flag_values() {
+        assert_eq!("auto".parse(), Ok(ColorChoice::Auto));
+        assert_eq!("always".parse(), Ok(ColorChoice::Always));
+        assert_eq!("never".parse(), Ok(ColorChoice::Never));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// Ways Variables and Data Interact: Clone ----------------------
+use std::io::{self, Write};
+
+use crate::demo_result::{Action, DemoResult};
+use crate::example::{Example, Rule, RunConfig};
+
+/// Contrasts a move, a clone and a copy, recording them in `result` and returning the original
+/// and the cloned `String`.
+///
+/// A `String` that has been moved can't be cloned afterwards, there is nothing left to clone:
+///
+/// ```compile_fail
+/// let s1 = String::from("hello");
+/// let s2 = s1; // s1 is moved to s2
+/// let my_s2 = s1.clone(); // error: borrow of moved value: `s1`
+/// println!("{} {}", s2, my_s2);
+/// ```
+pub fn clone_demo(result: &mut DemoResult, config: &RunConfig) -> io::Result<(String, String)> {
+    // move: s1 is invalidated, only s2 can be used from now on
+    let s1 = String::from("hello");
+    let s2 = s1;
+    result.step(Action::Create, &["s1"]);
+    result.step(Action::Move, &["s1", "s2"]);
+    writeln!(result, "move:  s2 = {} (s1 is no longer usable)", s2)?;
+    config.trace(result, "s2", &s2)?;
+
+    // clone: the heap data is deep copied, so both owners stay usable
+    let my_s1 = String::from("hello");
+    let my_s2 = my_s1.clone(); // deep copy
+    result.step(Action::Create, &["my_s1"]);
+    result.step(Action::Clone, &["my_s1", "my_s2"]);
+    writeln!(result, "clone: my_s1 = {}, my_s2 = {}", my_s1, my_s2)?;
+    config.trace(result, "my_s1", &my_s1)?;
+    config.trace(result, "my_s2", &my_s2)?; // a different pointer, the clone has its own buffer
+
+    // copy: an i32 lives on the stack and is copied, no move or clone needed
+    let n1 = 5;
+    let n2 = n1;
+    result.step(Action::Create, &["n1"]);
+    result.step(Action::Copy, &["n1", "n2"]);
+    writeln!(result, "copy:  n1 = {}, n2 = {}", n1, n2)?;
+
+    Ok((my_s1, my_s2))
+}
+
+// Stack-Only Data: Copy ----------------------------------------
+// Types such as integers that have a known size at compile time are stored entirely on the stack, so copies of the actual values are quick to make
+
+/// Copies an integer that lives entirely on the stack.
+pub fn stack_copy() -> (i32, i32) {
+    let my_p = 5;
+    let my_q = my_p;
+    (my_p, my_q)
+}
+
+/// Runs [`clone_demo`] into a fresh result.
+pub fn demo_clone(config: &RunConfig) -> io::Result<DemoResult> {
+    let mut result = DemoResult::new("clone");
+    clone_demo(&mut result, config)?;
+    Ok(result)
+}
+
+/// Copies an integer, recording the copy.
+pub fn demo_copy() -> io::Result<DemoResult> {
+    let mut result = DemoResult::new("copy");
+    let (my_p, my_q) = stack_copy();
+    result.step(Action::Create, &["my_p"]);
+    result.step(Action::Copy, &["my_p", "my_q"]);
+    writeln!(result, "my_p = {}, my_q = {}", my_p, my_q)?;
+    Ok(result)
+}
+
+pub struct CloneExample;
+
+impl Example for CloneExample {
+    fn name(&self) -> &str {
+        "clone"
+    }
+
+    fn description(&self) -> &str {
+        "deep copies of heap data"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("clone_copy.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()> {
+        demo_clone(config)?.render(out)
+    }
+
+    fn result(&self, config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        demo_clone(config).map(Some)
+    }
+}
+
+pub struct CopyExample;
+
+impl Example for CopyExample {
+    fn name(&self) -> &str {
+        "copy"
+    }
+
+    fn description(&self) -> &str {
+        "stack-only data is copied, not moved"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("clone_copy.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        demo_copy()?.render(out)
+    }
+
+    fn result(&self, _config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        demo_copy().map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_equals_the_original() {
+        let (my_s1, my_s2) =
+            clone_demo(&mut DemoResult::new("clone"), &RunConfig::default()).unwrap();
+        assert_eq!(my_s1, my_s2);
+        assert_eq!(my_s1, "hello");
+    }
+
+    #[test]
+    fn clone_leaves_both_strings_usable() {
+        let (mut my_s1, my_s2) =
+            clone_demo(&mut DemoResult::new("clone"), &RunConfig::default()).unwrap();
+        // separate heap buffers, so changing one leaves the other alone
+        assert_ne!(my_s1.as_ptr(), my_s2.as_ptr());
+        my_s1.push_str(" world");
+        assert_eq!(my_s1, "hello world");
+        assert_eq!(my_s2, "hello");
+    }
+
+    #[test]
+    fn clone_demo_walks_through_move_clone_and_copy() {
+        let result = demo_clone(&RunConfig::default()).unwrap();
+        let steps: Vec<&str> = result
+            .output
+            .lines()
+            .map(|l| l.split(':').next().unwrap())
+            .collect();
+        assert_eq!(steps, ["move", "clone", "copy"]);
+    }
+
+    #[test]
+    fn demo_clone_records_one_step_of_each_kind() {
+        let result = demo_clone(&RunConfig::default()).unwrap();
+        let actions: Vec<Action> = result
+            .steps
+            .iter()
+            .filter(|step| step.action != Action::Create)
+            .map(|step| step.action)
+            .collect();
+        assert_eq!(actions, [Action::Move, Action::Clone, Action::Copy]);
+        let clone = result.steps_of(Action::Clone).next().unwrap();
+        assert_eq!(clone.vars, ["my_s1", "my_s2"]);
+    }
+
+    #[test]
+    fn demo_copy_records_a_copy_and_no_move() {
+        let result = demo_copy().unwrap();
+        assert_eq!(result.steps_of(Action::Copy).count(), 1);
+        assert_eq!(result.steps_of(Action::Move).count(), 0);
+    }
+
+    #[test]
+    fn stack_values_are_copied() {
+        assert_eq!(stack_copy(), (5, 5));
+    }
+}
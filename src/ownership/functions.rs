@@ -0,0 +1,236 @@
+// Ownership and Functions --------------------------------------
+use std::io::{self, Write};
+
+use crate::demo_result::{Action, DemoResult};
+use crate::example::{Example, Rule, RunConfig};
+use crate::scope_tracker::ScopeTracker;
+
+/// Moves the String in and drops it at the end, so the caller can't use its binding afterwards:
+///
+/// ```compile_fail
+/// use ownership::ownership::functions::takes_ownership;
+///
+/// let my_str = String::from("hello");
+/// takes_ownership(my_str, &mut std::io::sink()).unwrap();
+/// println!("{}", my_str); // error: borrow of moved value: `my_str`
+/// ```
+pub fn takes_ownership(some_string: String, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}", some_string)
+} // some_string goes out of scope and `drop` is called. The backing memory is freed
+
+pub fn makes_copy(some_integer: i32, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}", some_integer)
+} // some_integer goes out of scope. Nothing special happens
+
+pub fn borrows_str(s: &str, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}", s)
+} // s goes out of scope, but it doesn't own the String it points into. Nothing is dropped
+
+pub fn borrows_mut(s: &mut String) {
+    s.push_str(", world");
+} // the caller owns the String, with the change, once the borrow ends
+
+// Return Values and Scope
+#[allow(clippy::let_and_return)] // the named owner makes the move out visible
+pub fn gives_ownership() -> String {
+    let some_string = String::from("hello");
+    some_string
+} // some_string is returned and moves out to the calling function
+
+pub fn takes_and_gives_back(a_string: String) -> String {
+    a_string
+} // a_string is returned and moves out to the calling function
+
+// Returning ownership of parameters
+pub fn calculate_length(s: String) -> (String, usize) {
+    let length = s.len(); // len() returns the length of a String
+    (s, length)
+}
+
+/// Moves values into and out of the functions above, recording every step.
+pub fn demo(config: &RunConfig) -> io::Result<DemoResult> {
+    let mut result = DemoResult::new("functions");
+    let mut tracker = ScopeTracker::new();
+    let live = "value is live";
+
+    // The same data goes through all four kinds of parameter, the one that consumes it last
+    let mut my_str = String::from("hello");
+    tracker.created("my_str");
+    result.step(Action::Create, &["my_str"]);
+    borrows_str(&my_str, &mut result)?; // my_str is only borrowed
+    result.step(Action::Borrow, &["my_str", "s"]);
+    writeln!(
+        result,
+        "after borrows_str, my_str is still usable: {}",
+        my_str
+    )?;
+    borrows_mut(&mut my_str); // borrowed mutably, changed in place
+    result.step(Action::Borrow, &["my_str", "s"]);
+    writeln!(
+        result,
+        "after borrows_mut, my_str is still usable: {}",
+        my_str
+    )?;
+
+    let my_x = my_str.len() as i32;
+    tracker.created("my_x");
+    result.step(Action::Create, &["my_x"]);
+    makes_copy(my_x, &mut result)?; // my_x value is copied to the function
+    tracker.created("some_integer");
+    tracker.dropped("some_integer").expect(live);
+    result.step(Action::Copy, &["my_x", "some_integer"]);
+    result.step(Action::Drop, &["some_integer"]);
+    writeln!(result, "after makes_copy, my_x is still usable: {}", my_x)?;
+
+    takes_ownership(my_str, &mut result)?; // my_str value is moved to the function
+    tracker.moved("my_str", "some_string").expect(live);
+    tracker.dropped("some_string").expect(live);
+    result.step(Action::Move, &["my_str", "some_string"]);
+    result.step(Action::Drop, &["some_string"]);
+    writeln!(
+        result,
+        "after takes_ownership, my_str is gone: it was moved in and dropped"
+    )?;
+
+    let _s_1 = gives_ownership();
+    tracker.created("some_string");
+    tracker.moved("some_string", "s_1").expect(live);
+    result.step(Action::Create, &["some_string"]);
+    result.step(Action::Move, &["some_string", "s_1"]);
+
+    let s_2 = String::from("hello");
+    tracker.created("s_2");
+    result.step(Action::Create, &["s_2"]);
+    config.trace(&mut result, "s_2", &s_2)?;
+    let s_3 = takes_and_gives_back(s_2);
+    config.trace(&mut result, "s_3", &s_3)?; // same pointer, the buffer came back
+    tracker.moved("s_2", "a_string").expect(live);
+    tracker.moved("a_string", "s_3").expect(live);
+    result.step(Action::Move, &["s_2", "a_string"]);
+    result.step(Action::Move, &["a_string", "s_3"]);
+
+    let s_4 = String::from("hello");
+    tracker.created("s_4");
+    result.step(Action::Create, &["s_4"]);
+    let (s_5, len) = calculate_length(s_4);
+    tracker.moved("s_4", "s").expect(live);
+    tracker.moved("s", "s_5").expect(live);
+    result.step(Action::Move, &["s_4", "s"]);
+    result.step(Action::Move, &["s", "s_5"]);
+    writeln!(result, "The length of '{}' is {}", s_5, len)?;
+
+    for name in ["s_5", "s_3", "s_1", "my_x"] {
+        tracker.dropped(name).expect(live);
+        result.step(Action::Drop, &[name]);
+    }
+    write!(result, "{}", tracker.report_styled(&config.style))?;
+    Ok(result)
+}
+
+pub struct FunctionsExample;
+
+impl Example for FunctionsExample {
+    fn name(&self) -> &str {
+        "functions"
+    }
+
+    fn description(&self) -> &str {
+        "passing and returning values moves or copies them"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("functions.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()> {
+        demo(config)?.render(out)
+    }
+
+    fn result(&self, config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        demo(config).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::demo_result::Step;
+
+    #[test]
+    fn takes_ownership_prints_the_moved_string() {
+        let mut out = Vec::new();
+        takes_ownership(String::from("hello"), &mut out).unwrap();
+        assert_eq!(out, b"hello\n");
+    }
+
+    #[test]
+    fn makes_copy_leaves_the_caller_value_usable() {
+        let mut out = Vec::new();
+        let x = 5;
+        makes_copy(x, &mut out).unwrap();
+        assert_eq!(out, b"5\n");
+        assert_eq!(x, 5);
+    }
+
+    #[test]
+    fn borrowing_functions_leave_the_string_with_the_caller() {
+        let mut out = Vec::new();
+        let mut s = String::from("hello");
+        borrows_str(&s, &mut out).unwrap();
+        borrows_mut(&mut s);
+        assert_eq!(out, b"hello\n");
+        assert_eq!(s, "hello, world");
+    }
+
+    #[test]
+    fn only_takes_ownership_consumes_the_string() {
+        let result = demo(&RunConfig::default()).unwrap();
+        let consumed: Vec<&Step> = result
+            .steps_of(Action::Move)
+            .filter(|step| step.vars[0] == "my_str")
+            .collect();
+        assert_eq!(
+            consumed,
+            [&Step::new(Action::Move, &["my_str", "some_string"])]
+        );
+        let borrowed = result
+            .steps_of(Action::Borrow)
+            .filter(|step| step.vars[0] == "my_str")
+            .count();
+        assert_eq!(borrowed, 2);
+        assert!(result
+            .output
+            .contains("after borrows_mut, my_str is still usable: hello, world\n"));
+    }
+
+    #[test]
+    fn ownership_moves_out_of_functions() {
+        assert_eq!(gives_ownership(), "hello");
+        assert_eq!(takes_and_gives_back(String::from("back")), "back");
+    }
+
+    #[test]
+    fn calculate_length_returns_the_string_with_its_length() {
+        let (s, len) = calculate_length(String::from("hello"));
+        assert_eq!(s, "hello");
+        assert_eq!(len, 5);
+    }
+
+    #[test]
+    fn demo_steps_follow_the_tracker() {
+        let result = demo(&RunConfig::default()).unwrap();
+        assert_eq!(result.steps_of(Action::Move).count(), 6);
+        assert_eq!(result.steps_of(Action::Copy).count(), 1);
+        // everything created is dropped, everything moved is gone
+        let created =
+            result.steps_of(Action::Create).count() + result.steps_of(Action::Copy).count();
+        let dropped = result.steps_of(Action::Drop).count();
+        assert_eq!(created, dropped);
+        let last = result.steps.last().unwrap();
+        assert_eq!(*last, Step::new(Action::Drop, &["my_x"]));
+    }
+}
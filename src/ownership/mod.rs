@@ -0,0 +1,6 @@
+// Ownership: who owns a value, how ownership is transferred, and when the value is dropped
+pub mod clone_copy;
+pub mod functions;
+pub mod moves;
+pub mod pass_through;
+pub mod strings;
@@ -0,0 +1,108 @@
+// Ways Variables and Data Interact: Move -----------------------
+use std::io::{self, Write};
+
+use crate::demo_result::{Action, DemoResult};
+use crate::example::{Example, Rule, RunConfig};
+use crate::scope_tracker::ScopeTracker;
+
+/// Integers are copied on assignment, so both bindings stay usable.
+pub fn copy_integer() -> (i32, i32) {
+    let x = 5;
+    let y = x;
+    (x, y)
+}
+
+/// Assigning a `String` moves it: after `let s2 = s1;` only `s2` is usable.
+#[allow(clippy::let_and_return)] // the intermediate binding is the point of the demo
+pub fn move_string() -> String {
+    // Double free error that Rust prevents
+    let s1 = String::from("hello"); // s1 is moved to s2
+    let s2 = s1;
+    s2
+}
+
+/// Copies an integer and moves a String, recording each step.
+pub fn demo(config: &RunConfig) -> io::Result<DemoResult> {
+    let mut result = DemoResult::new("moves");
+    let mut tracker = ScopeTracker::new();
+
+    let (x, y) = copy_integer();
+    tracker.created("x");
+    tracker.created("y"); // a copy is a new value, x stays live
+    result.step(Action::Create, &["x"]);
+    result.step(Action::Copy, &["x", "y"]);
+    writeln!(result, "x = {}, y = {}", x, y)?;
+
+    let s2 = move_string();
+    tracker.created("s1");
+    tracker.moved("s1", "s2").expect("s1 is live");
+    result.step(Action::Create, &["s1"]);
+    result.step(Action::Move, &["s1", "s2"]);
+    writeln!(result, "s2 = {} (s1 was moved into s2)", s2)?;
+    config.trace(&mut result, "s2", &s2)?;
+
+    // end of scope, in reverse order; s1 was moved so there is nothing to drop for it
+    for name in ["s2", "y", "x"] {
+        tracker.dropped(name).expect("value is live");
+        result.step(Action::Drop, &[name]);
+    }
+    write!(result, "{}", tracker.report_styled(&config.style))?;
+    Ok(result)
+}
+
+pub struct MovesExample;
+
+impl Example for MovesExample {
+    fn name(&self) -> &str {
+        "moves"
+    }
+
+    fn description(&self) -> &str {
+        "assigning a String moves ownership"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("moves.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()> {
+        demo(config)?.render(out)
+    }
+
+    fn result(&self, config: &RunConfig) -> io::Result<Option<DemoResult>> {
+        demo(config).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_are_copied() {
+        assert_eq!(copy_integer(), (5, 5));
+    }
+
+    #[test]
+    fn moved_string_keeps_its_contents() {
+        assert_eq!(move_string(), "hello");
+    }
+
+    #[test]
+    fn demo_records_exactly_one_move() {
+        let result = demo(&RunConfig::default()).unwrap();
+        let moves: Vec<_> = result.steps_of(Action::Move).collect();
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].vars, ["s1", "s2"]);
+        let dropped: Vec<&str> = result
+            .steps_of(Action::Drop)
+            .map(|step| step.vars[0].as_str())
+            .collect();
+        assert_eq!(dropped, ["s2", "y", "x"]); // never s1, it was moved
+        assert_eq!(result.steps_of(Action::Copy).count(), 1);
+    }
+}
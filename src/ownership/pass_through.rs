@@ -0,0 +1,119 @@
+// Generic Pass Through -----------------------------------------
+// `takes_and_gives_back` only works for String, but moving a value in and handing it back out works
+// the same for any type. Copy types are copied on the way in, everything else is moved
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Takes ownership of any value and gives it back.
+///
+/// For a type that isn't Copy the caller's binding is moved, so it can't be used afterwards:
+///
+/// ```compile_fail
+/// use ownership::ownership::pass_through::pass_through;
+///
+/// let v = vec![1u8, 2, 3];
+/// let back = pass_through(v);
+/// println!("{:?}", v); // error: borrow of moved value: `v`
+/// ```
+pub fn pass_through<T>(value: T) -> T {
+    value
+}
+
+/// Takes ownership of two values and gives them back in swapped order.
+pub fn pass_through_pair<T, U>(a: T, b: U) -> (U, T) {
+    (b, a)
+}
+
+/// A custom type without Copy, it moves like a String does.
+#[derive(Debug, PartialEq)]
+pub struct Ticket {
+    pub id: u32,
+    pub holder: String,
+}
+
+pub struct PassThroughExample;
+
+impl Example for PassThroughExample {
+    fn name(&self) -> &str {
+        "pass-through"
+    }
+
+    fn description(&self) -> &str {
+        "a generic takes_and_gives_back for any type"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("pass_through.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let s = pass_through(String::from("hello"));
+        writeln!(out, "String:   {:?} (moved in and back out)", s)?;
+
+        let bytes = pass_through(vec![1u8, 2, 3]);
+        writeln!(out, "Vec<u8>:  {:?} (moved in and back out)", bytes)?;
+
+        let ticket = pass_through(Ticket {
+            id: 7,
+            holder: String::from("Ferris"),
+        });
+        writeln!(out, "Ticket:   {:?} (moved in and back out)", ticket)?;
+
+        let n = 5;
+        let m = pass_through(n);
+        writeln!(out, "i32:      {} (copied, n = {} is still usable)", m, n)?;
+
+        let (ticket, s) = pass_through_pair(s, ticket);
+        writeln!(
+            out,
+            "pair:     ({:?}, {:?}) (both moved and swapped)",
+            ticket, s
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pass_through_returns_the_same_value() {
+        let s = String::from("hello");
+        let ptr = s.as_ptr();
+        let back = pass_through(s);
+        assert_eq!(back, "hello");
+        assert_eq!(back.as_ptr(), ptr); // moved, not copied
+
+        assert_eq!(pass_through(vec![1u8, 2]), [1, 2]);
+        assert_eq!(pass_through(42), 42);
+        let ticket = Ticket {
+            id: 1,
+            holder: String::from("a"),
+        };
+        assert_eq!(
+            pass_through(ticket),
+            Ticket {
+                id: 1,
+                holder: String::from("a")
+            }
+        );
+    }
+
+    #[test]
+    fn pass_through_pair_round_trips() {
+        let (b, a) = pass_through_pair(String::from("a"), 2);
+        assert_eq!((b, a.as_str()), (2, "a"));
+
+        let (a, b) = {
+            let (x, y) = pass_through_pair(vec![1], String::from("b"));
+            pass_through_pair(x, y)
+        };
+        assert_eq!(a, [1]); // swapped twice, back in the original order
+        assert_eq!(b, "b");
+    }
+}
@@ -0,0 +1,84 @@
+// String type - Heap allocated -------------------------------
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::inspect;
+
+/// Creates a heap allocated `String` from a string literal.
+pub fn string_from() -> String {
+    // the :: operator allows us to namespace this particular from function under the String type rather than using some sort of name like string_from
+    String::from("hello")
+}
+
+/// Grows a `String` in place with `push_str`.
+pub fn push_str_demo() -> String {
+    let mut str = String::from("Hello");
+    str.push_str(", World!"); // push_str() appends a literal to a String
+    str
+}
+
+// Memory and Allocation ---------------------------------------
+// string literals are immutable and hardcoded into the binary, we know the contents at compile time, so the text is hardcoded directly into the final executable
+// String type is allocated on the heap, which means the memory must be requested from the operating system at runtime
+// The memory must be requested when we call String::from and the memory is automatically returned once the variable that owns it goes out of scope, so using drop, this is done by the Rust runtime when closing the curly braces of the scope
+// Memory lenght: how much memory, in bytes, the is currently used
+// Memory capacity: the total amount of memory, in bytes, that has received from the operating system
+
+pub struct StringsExample;
+
+impl Example for StringsExample {
+    fn name(&self) -> &str {
+        "strings"
+    }
+
+    fn description(&self) -> &str {
+        "heap allocated, growable text"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("strings.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()> {
+        let from = string_from();
+        writeln!(out, "{}", from)?;
+        config.trace(out, "String::from", &from)?;
+        let pushed = push_str_demo();
+        writeln!(out, "{}", pushed)?;
+        config.trace(out, "push_str", &pushed)?;
+
+        let mut s = String::from("Hello");
+        writeln!(out, "{:<24} {}", format!("{:?}", s), inspect::describe(&s))?;
+        for part in [", World!", " Growing", " past the capacity"] {
+            s.push_str(part);
+            writeln!(
+                out,
+                "{:<24} {}",
+                format!("+ {:?}", part),
+                inspect::describe(&s)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_from_copies_the_literal_to_the_heap() {
+        let s = string_from();
+        assert_eq!(s, "hello");
+        assert!(s.capacity() >= s.len());
+    }
+
+    #[test]
+    fn push_str_appends_in_place() {
+        assert_eq!(push_str_demo(), "Hello, World!");
+    }
+}
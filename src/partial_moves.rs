@@ -0,0 +1,134 @@
+// Partial Moves ------------------------------------------------
+// Moving one field out of a struct or tuple only moves that field. The rest stays usable, but the
+// value as a whole can't be used any more because part of it is gone
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Person {
+    pub name: String,
+    pub age: u32,
+}
+
+/// Moves `name` out of `person` and still reads the Copy `age` field afterwards.
+///
+/// After the move `person` is partially moved, so it can't be used as a whole:
+///
+/// ```compile_fail
+/// use ownership::partial_moves::Person;
+///
+/// let person = Person { name: String::from("Ana"), age: 30 };
+/// let name = person.name;
+/// println!("{:?}", person); // error: borrow of partially moved value: `person`
+/// ```
+pub fn take_name(person: Person) -> (String, u32) {
+    let name = person.name; // moves the String out
+    let age = person.age; // still fine, age was never moved
+    (name, age)
+}
+
+/// Moves the String out of the tuple; the `i32` is copied, the tuple itself is partially moved.
+///
+/// ```compile_fail
+/// let pair = (String::from("hello"), 5);
+/// let (s, n) = pair;
+/// println!("{:?}", pair); // error: borrow of partially moved value: `pair`
+/// ```
+pub fn split_pair(pair: (String, i32)) -> (String, i32) {
+    let (s, n) = pair;
+    let again = pair.1; // the i32 was copied out, so it can be read again
+    (s, n + again)
+}
+
+/// Struct update syntax moves the non-Copy fields it takes from `base`.
+///
+/// `base.name` now belongs to the new Person:
+///
+/// ```compile_fail
+/// use ownership::partial_moves::Person;
+///
+/// let base = Person { name: String::from("Ana"), age: 30 };
+/// let older = Person { age: 31, ..base };
+/// println!("{}", base.name); // error: borrow of moved value: `base.name`
+/// ```
+pub fn birthday(base: Person) -> (Person, u32) {
+    let older = Person {
+        age: base.age + 1,
+        ..base // moves base.name
+    };
+    (older, base.age) // base.age is Copy, reading it is fine
+}
+
+pub struct PartialMovesExample;
+
+impl Example for PartialMovesExample {
+    fn name(&self) -> &str {
+        "partial-moves"
+    }
+
+    fn description(&self) -> &str {
+        "moving single fields out of structs and tuples"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("partial_moves.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let person = Person {
+            name: String::from("Ana"),
+            age: 30,
+        };
+        let (name, age) = take_name(person);
+        writeln!(out, "moved name {:?} out, age {} still readable", name, age)?;
+
+        let (s, n) = split_pair((String::from("hello"), 5));
+        writeln!(out, "tuple: moved {:?}, copied 5 twice: {}", s, n)?;
+
+        let (older, before) = birthday(Person { name, age });
+        writeln!(out, "struct update: {:?}, base.age was {}", older, before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ana() -> Person {
+        Person {
+            name: String::from("Ana"),
+            age: 30,
+        }
+    }
+
+    #[test]
+    fn moving_the_name_leaves_the_age_readable() {
+        assert_eq!(take_name(ana()), (String::from("Ana"), 30));
+    }
+
+    #[test]
+    fn tuple_destructure_moves_only_the_string() {
+        assert_eq!(split_pair((String::from("x"), 2)), (String::from("x"), 4));
+    }
+
+    #[test]
+    fn struct_update_moves_the_name_into_the_new_value() {
+        let (older, before) = birthday(ana());
+        assert_eq!(older.name, "Ana");
+        assert_eq!(older.age, 31);
+        assert_eq!(before, 30);
+    }
+
+    #[test]
+    fn fields_can_be_moved_out_one_at_a_time() {
+        let person = ana();
+        let name = person.name;
+        let age = person.age;
+        assert_eq!((name.as_str(), age), ("Ana", 30));
+    }
+}
@@ -0,0 +1,135 @@
+// Pattern Matching and Ownership ---------------------------------
+// Matching on a value binds its parts by value, which moves them. Matching on a reference binds
+// them by reference instead (default binding modes), and `ref` / `ref mut` ask for that
+// explicitly when matching on a place
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Matches on an owned `Option<String>`: `Some(s)` moves the String out of it.
+///
+/// The Option is partially moved afterwards and can't be used again:
+///
+/// ```compile_fail
+/// let opt = Some(String::from("hello"));
+/// match opt {
+///     Some(s) => println!("{}", s), // s takes ownership of the String
+///     None => {}
+/// }
+/// println!("{:?}", opt); // error: borrow of partially moved value: `opt`
+/// ```
+#[allow(clippy::manual_unwrap_or_default)] // the explicit match is what moves
+pub fn into_inner_or_default(opt: Option<String>) -> String {
+    match opt {
+        Some(s) => s, // moved out of opt
+        None => String::new(),
+    }
+}
+
+/// Matches on `&Option<String>`: default binding modes make `s` a `&String`, nothing moves.
+pub fn inner_len(opt: &Option<String>) -> usize {
+    match opt {
+        Some(s) => s.len(), // s: &String
+        None => 0,
+    }
+}
+
+/// Appends `!` to the inner String in place. Matching on `&mut Option<String>` binds `s` as
+/// `&mut String`.
+#[allow(clippy::single_match)] // both arms spelled out on purpose
+pub fn upgrade(opt: &mut Option<String>) {
+    match opt {
+        Some(s) => s.push('!'),
+        None => {}
+    }
+}
+
+/// Same as [`upgrade`], spelled the pre-2018 way: match on the place and ask for `ref mut`.
+#[allow(clippy::single_match)]
+pub fn upgrade_ref_mut(opt: &mut Option<String>) {
+    match *opt {
+        Some(ref mut s) => s.push('!'),
+        None => {}
+    }
+}
+
+pub struct PatternsExample;
+
+impl Example for PatternsExample {
+    fn name(&self) -> &str {
+        "patterns"
+    }
+
+    fn description(&self) -> &str {
+        "match moves or borrows depending on what it matches"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("patterns.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut opt = Some(String::from("hello"));
+        writeln!(
+            out,
+            "inner_len(&opt) = {}, opt still usable: {:?}",
+            inner_len(&opt),
+            opt
+        )?;
+        upgrade(&mut opt);
+        upgrade_ref_mut(&mut opt);
+        writeln!(out, "after two upgrades: {:?}", opt)?;
+        let s = into_inner_or_default(opt); // opt is moved into the function
+        writeln!(out, "moved out by value: {}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrade_appends_to_some() {
+        let mut opt = Some(String::from("hi"));
+        upgrade(&mut opt);
+        assert_eq!(opt.as_deref(), Some("hi!"));
+    }
+
+    #[test]
+    fn upgrade_leaves_none_alone() {
+        let mut opt = None;
+        upgrade(&mut opt);
+        assert_eq!(opt, None);
+    }
+
+    #[test]
+    fn ref_mut_version_matches_upgrade() {
+        for start in [Some(String::from("hi")), None] {
+            let (mut a, mut b) = (start.clone(), start);
+            upgrade(&mut a);
+            upgrade_ref_mut(&mut b);
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn matching_a_reference_borrows() {
+        let opt = Some(String::from("hello"));
+        assert_eq!(inner_len(&opt), 5);
+        assert_eq!(inner_len(&None), 0);
+        assert!(opt.is_some());
+    }
+
+    #[test]
+    fn matching_by_value_moves_the_string_out() {
+        let opt = Some(String::from("hello"));
+        let ptr = opt.as_ref().unwrap().as_ptr();
+        let s = into_inner_or_default(opt);
+        assert_eq!(s.as_ptr(), ptr); // same heap buffer, moved rather than copied
+        assert_eq!(into_inner_or_default(None), "");
+    }
+}
@@ -0,0 +1,150 @@
+// Pin ----------------------------------------------------------
+// A struct holding a raw pointer into itself is only right until it moves: a move copies the bytes
+// to a new address, and the pointer still holds the old one. `Pin<Box<T>>` keeps the value at one
+// heap address for good, and PhantomPinned stops safe code from moving it back out
+use std::io::{self, Write};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Four bytes and a pointer that is supposed to point at the first of them.
+pub struct SelfPointing {
+    data: [u8; 4],
+    ptr: *const u8,
+    // opts out of Unpin, so a Pin of it never hands out the `&mut` that mem::swap would need
+    _pinned: PhantomPinned,
+}
+
+impl SelfPointing {
+    fn new(data: [u8; 4]) -> SelfPointing {
+        SelfPointing {
+            data,
+            ptr: ptr::null(),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// Where the bytes are right now.
+    pub fn data_address(&self) -> usize {
+        self.data.as_ptr() as usize
+    }
+
+    /// Whether the pointer still points at this value's own bytes.
+    pub fn points_at_itself(&self) -> bool {
+        ptr::eq(self.ptr, self.data.as_ptr())
+    }
+}
+
+/// Sets up the self-pointer on the stack, then moves the value into a Box.
+/// Returns the address of the bytes before and after the move.
+pub fn address_changes_on_move() -> (usize, usize) {
+    let mut value = SelfPointing::new(*b"rust");
+    value.ptr = value.data.as_ptr(); // storing a raw pointer is safe, only reading through it isn't
+    let before = value.data_address();
+    let moved = Box::new(value); // the bytes are copied to the heap, `ptr` still says `before`
+    debug_assert!(!moved.points_at_itself());
+    (before, moved.data_address())
+}
+
+/// A SelfPointing set up on the heap, where it will stay.
+///
+/// Once pinned it can't be moved out again, not even by swapping:
+///
+/// ```compile_fail
+/// use ownership::pin_demo::pinned;
+///
+/// let mut a = pinned(*b"aaaa");
+/// let mut b = pinned(*b"bbbb");
+/// // error: `PhantomPinned` cannot be unpinned, so get_mut isn't available
+/// std::mem::swap(a.as_mut().get_mut(), b.as_mut().get_mut());
+/// ```
+pub fn pinned(data: [u8; 4]) -> Pin<Box<SelfPointing>> {
+    let mut boxed = Box::pin(SelfPointing::new(data));
+    let address = boxed.data.as_ptr();
+    // SAFETY: get_unchecked_mut hands out a `&mut` to a pinned value, which is only sound if
+    // nothing is moved out through it. Writing one field of the value in place moves nothing,
+    // and the `&mut` is gone at the end of the statement.
+    unsafe {
+        boxed.as_mut().get_unchecked_mut().ptr = address;
+    }
+    boxed
+}
+
+pub struct PinExample;
+
+impl Example for PinExample {
+    fn name(&self) -> &str {
+        "pin"
+    }
+
+    fn description(&self) -> &str {
+        "a value pointing into itself must not move"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("pin_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let (before, after) = address_changes_on_move();
+        writeln!(
+            out,
+            "unpinned: data at {:#x}, after a move at {:#x}, the self-pointer still says {:#x}",
+            before, after, before
+        )?;
+        let mut pins: Vec<Pin<Box<SelfPointing>>> = Vec::new();
+        let first = pinned(*b"pin!");
+        let address = first.data_address();
+        pins.push(first);
+        for i in 0..8 {
+            pins.push(pinned([i; 4])); // the Vec reallocates, moving the Boxes but not what they point to
+        }
+        writeln!(
+            out,
+            "pinned: data at {:#x}, after 8 more pushes at {:#x}, points at itself: {}",
+            address,
+            pins[0].data_address(),
+            pins.iter().all(|p| p.points_at_itself())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moving_leaves_the_self_pointer_behind() {
+        let (before, after) = address_changes_on_move();
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn pinned_values_point_at_themselves() {
+        let p = pinned(*b"abcd");
+        assert!(p.points_at_itself());
+        assert_eq!(p.data, *b"abcd");
+    }
+
+    #[test]
+    fn pinned_addresses_survive_vec_growth() {
+        let mut pins = Vec::with_capacity(1);
+        let mut addresses = Vec::new();
+        for i in 0..32 {
+            let p = pinned([i; 4]);
+            addresses.push(p.data_address());
+            pins.push(p);
+        }
+        assert!(pins.capacity() >= 32);
+        for (p, address) in pins.iter().zip(addresses) {
+            assert_eq!(p.data_address(), address);
+            assert!(p.points_at_itself());
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// Progress -----------------------------------------------------
+// Which examples have been run and how the quizzes went, kept in a small JSON file between
+// sessions. Saving writes a temp file next to the real one and renames it over, so a reader never
+// sees half a file, even with two runs saving at once
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Where progress is kept unless `--progress-file` says otherwise.
+pub const DEFAULT_FILE: &str = ".ownership-progress.json";
+
+/// Bumped whenever the file format changes, see [`Progress::from_json`].
+pub const VERSION: u64 = 1;
+
+static NEXT_TEMP: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuizScore {
+    pub score: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    pub version: u64,
+    /// Names of the examples run at least once.
+    pub completed: BTreeSet<String>,
+    /// Every finished quiz, oldest first.
+    pub quiz_scores: Vec<QuizScore>,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Progress {
+            version: VERSION,
+            completed: BTreeSet::new(),
+            quiz_scores: Vec::new(),
+        }
+    }
+}
+
+impl Progress {
+    /// Parses a progress file of any known version, migrating it to the current one.
+    pub fn from_json(json: &str) -> Result<Progress, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        match value.get("version").and_then(Value::as_u64) {
+            // older versions get a migration arm here as the format changes
+            Some(VERSION) => serde_json::from_value(value).map_err(|e| e.to_string()),
+            Some(v) => Err(format!("unknown progress file version {}", v)),
+            None => Err(String::from("missing version")),
+        }
+    }
+
+    /// Reads the progress at `path`, starting fresh if there is none.
+    ///
+    /// A file that can't be parsed is renamed to `<path>.bak` and replaced by an empty one.
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(e) => return Err(e),
+        };
+        match Progress::from_json(&json) {
+            Ok(progress) => Ok(progress),
+            Err(_) => {
+                fs::rename(path, backup_path(path))?;
+                let fresh = Progress::default();
+                fresh.save(path)?;
+                Ok(fresh)
+            }
+        }
+    }
+
+    /// Writes to a temp file in the same directory, then renames it over `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let temp = sibling(
+            path,
+            &format!(
+                ".tmp.{}.{}",
+                process::id(),
+                NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+            ),
+        );
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        if let Err(e) = fs::write(&temp, json).and_then(|()| fs::rename(&temp, path)) {
+            let _ = fs::remove_file(&temp); // may not exist, the error we report is `e`
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    pub fn mark_completed(&mut self, name: &str) {
+        self.completed.insert(name.to_string());
+    }
+
+    pub fn record_quiz(&mut self, score: usize, total: usize) {
+        self.quiz_scores.push(QuizScore { score, total });
+    }
+
+    /// How many of `names` have been completed, ignoring examples that no longer exist.
+    pub fn completed_of<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> usize {
+        names
+            .into_iter()
+            .filter(|name| self.completed.contains(*name))
+            .count()
+    }
+}
+
+/// Where a corrupt progress file is moved before it's recreated.
+pub fn backup_path(path: &Path) -> PathBuf {
+    sibling(path, ".bak")
+}
+
+/// `path` with `suffix` appended to its file name.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raii::TempDir;
+    use std::thread;
+
+    #[test]
+    fn first_run_starts_empty_without_creating_a_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_FILE);
+        let progress = Progress::load(&path).unwrap();
+        assert_eq!(progress, Progress::default());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn updates_accumulate_across_loads() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_FILE);
+        let mut progress = Progress::load(&path).unwrap();
+        progress.mark_completed("moves");
+        progress.save(&path).unwrap();
+
+        let mut progress = Progress::load(&path).unwrap();
+        progress.mark_completed("moves");
+        progress.mark_completed("strings");
+        progress.record_quiz(3, 12);
+        progress.save(&path).unwrap();
+
+        let progress = Progress::load(&path).unwrap();
+        assert_eq!(progress.completed.len(), 2);
+        assert_eq!(
+            progress.quiz_scores,
+            [QuizScore {
+                score: 3,
+                total: 12
+            }]
+        );
+        assert_eq!(progress.completed_of(["moves", "clone", "strings"]), 2);
+    }
+
+    #[test]
+    fn corrupt_file_is_backed_up_and_recreated() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_FILE);
+        fs::write(&path, "{ not json").unwrap();
+        let progress = Progress::load(&path).unwrap();
+        assert_eq!(progress, Progress::default());
+        assert_eq!(
+            fs::read_to_string(backup_path(&path)).unwrap(),
+            "{ not json"
+        );
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let future = r#"{"version": 2, "completed": [], "quiz_scores": []}"#;
+        assert_eq!(
+            Progress::from_json(future).unwrap_err(),
+            "unknown progress file version 2"
+        );
+        assert!(Progress::from_json(r#"{"completed": []}"#).is_err());
+    }
+
+    #[test]
+    fn concurrent_saves_never_leave_a_partial_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_FILE);
+        thread::scope(|s| {
+            for t in 0..4 {
+                let path = &path;
+                s.spawn(move || {
+                    for i in 0..25 {
+                        let mut progress = Progress::default();
+                        progress.mark_completed(&format!("example-{}-{}", t, i));
+                        progress.save(path).unwrap();
+                        // whichever save won, the file always parses
+                        Progress::from_json(&fs::read_to_string(path).unwrap()).unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(Progress::load(&path).unwrap().completed.len(), 1);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1); // no temp files left behind
+    }
+}
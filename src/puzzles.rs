@@ -0,0 +1,330 @@
+// Fix the Code -------------------------------------------------
+// `cargo run -- puzzles` shows a snippet the borrow checker rejects and asks which change fixes it.
+// Every snippet is a file under tests/compile_fail/, so trybuild proves it still fails, and every
+// correct fix is a function below with a test, so the compiler proves the answer still works
+use crate::example::Rule;
+use crate::quiz::Question;
+
+pub struct Puzzle {
+    /// The file under tests/compile_fail/ holding the broken snippet, without `.rs`.
+    pub name: &'static str,
+    pub rule: Rule,
+    pub broken: &'static str,
+    pub fixes: &'static [&'static str],
+    /// Index into `fixes`.
+    pub correct: usize,
+    pub explanation: &'static str,
+    /// The broken snippet with the correct fix applied, returning what it would print.
+    pub fixed: fn() -> String,
+}
+
+impl Puzzle {
+    /// The puzzle as a quiz question, the snippet indented under the prompt.
+    pub fn question(&self) -> Question {
+        let code: String = self
+            .broken
+            .lines()
+            .map(|line| format!("\n    {}", line).trim_end().to_string())
+            .collect();
+        Question {
+            prompt: format!(
+                "(rule {}) which change makes this compile?\n{}\n",
+                self.rule.number(),
+                code
+            ),
+            choices: self.fixes.iter().map(|f| f.to_string()).collect(),
+            correct: self.correct,
+            explanation: self.explanation.to_string(),
+        }
+    }
+}
+
+/// Every puzzle, in the order `cargo run -- puzzles` asks them.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        name: "use_after_move",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/use_after_move.rs"),
+        fixes: &[
+            "move the first println! after the second one",
+            "`let s2 = s1.clone();`",
+            "`let mut s2 = s1;`",
+        ],
+        correct: 1,
+        explanation: "clone gives s2 its own heap buffer, so s1 keeps owning the first one.",
+        fixed: use_after_move,
+    },
+    Puzzle {
+        name: "two_mut_borrows",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/two_mut_borrows.rs"),
+        fixes: &[
+            "make r1 `&s`, only r2 needs to be mutable",
+            "declare `s` without `mut`",
+            "print r1 before `let r2 = &mut s;`, and r2 on its own after",
+        ],
+        correct: 2,
+        explanation: "a borrow ends at its last use, so r1 is done before r2 starts.",
+        fixed: two_mut_borrows,
+    },
+    Puzzle {
+        name: "shared_and_mut_borrow",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/shared_and_mut_borrow.rs"),
+        fixes: &[
+            "print refer_1 and refer_2 before taking refer_3",
+            "declare refer_1 and refer_2 with `let mut`",
+            "declare refer_3 first, above refer_1 and refer_2",
+        ],
+        correct: 0,
+        explanation: "the shared borrows end at their last use, after that a mutable one may start. \
+                      The order of the lets doesn't matter while all three are used together.",
+        fixed: shared_and_mut_borrow,
+    },
+    Puzzle {
+        name: "dangling_reference",
+        rule: Rule::DropAtScopeEnd,
+        broken: include_str!("../tests/compile_fail/dangling_reference.rs"),
+        fixes: &[
+            "return `&s.clone()` instead of `&s`",
+            "return the String itself: `fn dangle() -> String` ending in `s`",
+            "return `&'static String`",
+        ],
+        correct: 1,
+        explanation: "returning s moves it to the caller, so it isn't dropped when dangle ends. \
+                      A clone would be dropped just the same, and no lifetime keeps a local alive.",
+        fixed: dangling_reference,
+    },
+    Puzzle {
+        name: "push_while_element_borrowed",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/push_while_element_borrowed.rs"),
+        fixes: &[
+            "`names.reserve(1);` before the push, so it can't reallocate",
+            "`let first = &mut names[0];`",
+            "`let first = names[0].clone();`",
+        ],
+        correct: 2,
+        explanation: "the clone owns its own copy of the name, so names isn't borrowed during the push. \
+                      The borrow checker doesn't know about capacity, reserve changes nothing for it.",
+        fixed: push_while_element_borrowed,
+    },
+    Puzzle {
+        name: "same_string_twice",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/same_string_twice.rs"),
+        fixes: &[
+            "take `src: &String` and call `append(&mut s, &s)`",
+            "clone the source first: `let mut src = s.clone(); append(&mut s, &mut src);`",
+            "call `append(&mut s, &mut *&mut s)`",
+        ],
+        correct: 1,
+        explanation: "the two arguments are now different Strings, each borrowed once. \
+                      `&mut s` and `&s` at the same time are still a conflict.",
+        fixed: same_string_twice,
+    },
+    Puzzle {
+        name: "mut_method_while_borrowed",
+        rule: Rule::OneOwner,
+        broken: include_str!("../tests/compile_fail/mut_method_while_borrowed.rs"),
+        fixes: &[
+            "`let first = playlist.first().clone();`",
+            "make `add` take `&self`",
+            "make `first` take `&mut self`",
+        ],
+        correct: 0,
+        explanation: "first is an owned String now, so playlist isn't borrowed when add is called. \
+                      add can't push through `&self`, and a `&mut self` borrow conflicts even more.",
+        fixed: mut_method_while_borrowed,
+    },
+    Puzzle {
+        name: "derive_copy_with_string",
+        rule: Rule::Owner,
+        broken: include_str!("../tests/compile_fail/derive_copy_with_string.rs"),
+        fixes: &[
+            "implement Copy by hand with `impl Copy for Label {}`",
+            "derive only Copy, without Clone",
+            "derive only Clone, and write `let copy = label.clone();`",
+        ],
+        correct: 2,
+        explanation: "a clone copies the heap buffer, so each Label owns its own text. \
+                      A type with a String field can't be Copy however Copy is implemented.",
+        fixed: derive_copy_with_string,
+    },
+];
+
+/// All the puzzles as quiz questions.
+pub fn questions() -> Vec<Question> {
+    PUZZLES.iter().map(Puzzle::question).collect()
+}
+
+// The correct fixes, each the snippet of its puzzle with the one change applied
+
+pub fn use_after_move() -> String {
+    let s1 = String::from("hello");
+    let s2 = s1.clone();
+    format!("{}, world!\n{}", s1, s2)
+}
+
+pub fn two_mut_borrows() -> String {
+    let mut s = String::from("hello");
+    let r1 = &mut s;
+    r1.push('!');
+    let mut printed = format!("{}, ", r1);
+    let r2 = &mut s;
+    r2.push('?');
+    printed.push_str(r2);
+    printed
+}
+
+pub fn shared_and_mut_borrow() -> String {
+    let mut string_1 = String::from("hello");
+    let refer_1 = &string_1;
+    let refer_2 = &string_1;
+    let shared = format!("{} and {}", refer_1, refer_2);
+    let refer_3 = &mut string_1;
+    refer_3.push_str(" world");
+    format!("{}, and {}", shared, refer_3)
+}
+
+pub fn dangling_reference() -> String {
+    #[allow(clippy::let_and_return)] // kept as close to the broken dangle as possible
+    fn no_dangle() -> String {
+        let s = String::from("hello");
+        s
+    }
+
+    no_dangle()
+}
+
+pub fn push_while_element_borrowed() -> String {
+    let mut names = vec![String::from("ana"), String::from("bo")];
+    let first = names[0].clone();
+    names.push(String::from("cy"));
+    format!("{} of {}", first, names.len())
+}
+
+pub fn same_string_twice() -> String {
+    fn append(dst: &mut String, src: &mut String) {
+        dst.push_str(src);
+        src.clear();
+    }
+
+    let mut s = String::from("hello");
+    let mut src = s.clone();
+    append(&mut s, &mut src);
+    format!("{} ({:?} left in src)", s, src)
+}
+
+pub fn mut_method_while_borrowed() -> String {
+    struct Playlist {
+        songs: Vec<String>,
+    }
+
+    impl Playlist {
+        fn first(&self) -> &String {
+            &self.songs[0]
+        }
+
+        fn add(&mut self, song: String) {
+            self.songs.push(song);
+        }
+    }
+
+    let mut playlist = Playlist {
+        songs: vec![String::from("one")],
+    };
+    let first = playlist.first().clone();
+    playlist.add(String::from("two"));
+    format!("{} of {}", first, playlist.songs.len())
+}
+
+pub fn derive_copy_with_string() -> String {
+    #[derive(Clone)]
+    struct Label {
+        text: String,
+    }
+
+    let label = Label {
+        text: String::from("hello"),
+    };
+    let copy = label.clone();
+    format!("{} {}", label.text, copy.text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::path::Path;
+
+    #[test]
+    fn every_broken_snippet_is_a_compile_fail_case() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+        for puzzle in PUZZLES {
+            let path = dir.join(format!("{}.rs", puzzle.name));
+            assert_eq!(
+                std::fs::read_to_string(&path).unwrap(),
+                puzzle.broken,
+                "{}",
+                path.display()
+            );
+            assert!(dir.join(format!("{}.stderr", puzzle.name)).is_file());
+        }
+    }
+
+    #[test]
+    fn puzzles_cover_every_rule() {
+        assert!(PUZZLES.len() >= 6);
+        for rule in [Rule::Owner, Rule::OneOwner, Rule::DropAtScopeEnd] {
+            assert!(PUZZLES.iter().any(|p| p.rule == rule), "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn questions_are_valid() {
+        for question in questions() {
+            assert!((2..=6).contains(&question.choices.len()));
+            assert!(question.correct < question.choices.len());
+        }
+    }
+
+    #[test]
+    fn question_shows_the_snippet() {
+        let question = PUZZLES[0].question();
+        assert!(question
+            .prompt
+            .starts_with("(rule 2) which change makes this compile?\n\n    // Rule 2"));
+        assert!(question.prompt.contains("\n        let s2 = s1;\n"));
+    }
+
+    #[test]
+    fn correct_answers_score_full_marks() {
+        let questions = questions();
+        let answers: String = PUZZLES
+            .iter()
+            .map(|p| format!("{}\n", p.correct + 1))
+            .collect();
+        let score =
+            crate::quiz::run(&questions, &mut Cursor::new(answers), &mut Vec::new()).unwrap();
+        assert_eq!(score, PUZZLES.len());
+    }
+
+    #[test]
+    fn fixes_compile_and_run() {
+        let outputs: Vec<String> = PUZZLES.iter().map(|p| (p.fixed)()).collect();
+        assert_eq!(
+            outputs,
+            [
+                "hello, world!\nhello",
+                "hello!, hello!?",
+                "hello and hello, and hello world",
+                "hello",
+                "ana of 3",
+                "hellohello (\"\" left in src)",
+                "one of 2",
+                "hello hello",
+            ]
+        );
+    }
+}
@@ -0,0 +1,355 @@
+// Quiz ---------------------------------------------------------
+// `cargo run -- quiz` asks multiple-choice questions about the rules the examples demonstrate.
+// The loop reads from any BufRead and writes to any Write, so tests can drive it from memory.
+// Questions come from questions.json, embedded at build time, or from `--file <path>`, and
+// `--shuffle [--seed <n>]` mixes up the order of the questions and of their choices
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::rng::XorShift;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Question {
+    pub prompt: String,
+    pub choices: Vec<String>,
+    /// Index into `choices`.
+    pub correct: usize,
+    pub explanation: String,
+}
+
+/// The question set used when no `--file` is given.
+pub const DEFAULT_QUESTIONS: &str = include_str!("../questions.json");
+
+#[derive(Debug)]
+pub enum QuizError {
+    Io(io::Error),
+    /// The file isn't valid JSON, or doesn't have the shape of a question list.
+    Json(serde_json::Error),
+    /// The question at `index` (counting from zero) parsed but makes no sense.
+    Invalid {
+        index: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuizError::Io(e) => write!(f, "{}", e),
+            QuizError::Json(e) => write!(f, "invalid question file: {}", e),
+            QuizError::Invalid { index, reason } => write!(f, "question {}: {}", index, reason),
+        }
+    }
+}
+
+impl std::error::Error for QuizError {}
+
+impl From<io::Error> for QuizError {
+    fn from(e: io::Error) -> Self {
+        QuizError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for QuizError {
+    fn from(e: serde_json::Error) -> Self {
+        QuizError::Json(e)
+    }
+}
+
+/// Parses and validates a JSON list of questions.
+pub fn parse_questions(json: &str) -> Result<Vec<Question>, QuizError> {
+    let questions: Vec<Question> = serde_json::from_str(json)?;
+    for (index, q) in questions.iter().enumerate() {
+        let reason = if !(2..=6).contains(&q.choices.len()) {
+            format!("needs 2 to 6 choices, has {}", q.choices.len())
+        } else if q.correct >= q.choices.len() {
+            format!(
+                "correct index {} is out of range for {} choices",
+                q.correct,
+                q.choices.len()
+            )
+        } else {
+            continue;
+        };
+        return Err(QuizError::Invalid { index, reason });
+    }
+    Ok(questions)
+}
+
+/// Loads the questions from `path`, or the embedded defaults when there is none.
+pub fn load_questions(path: Option<&Path>) -> Result<Vec<Question>, QuizError> {
+    match path {
+        Some(path) => parse_questions(&fs::read_to_string(path)?),
+        None => parse_questions(DEFAULT_QUESTIONS),
+    }
+}
+
+/// Shuffles the questions and the choices of each one, keeping `correct` on the right choice.
+pub fn shuffle(questions: &mut [Question], rng: &mut XorShift) {
+    rng.shuffle(questions);
+    for question in questions.iter_mut() {
+        let mut order: Vec<usize> = (0..question.choices.len()).collect();
+        rng.shuffle(&mut order);
+        question.correct = order
+            .iter()
+            .position(|&i| i == question.correct)
+            .expect("order is a permutation of the choice indices");
+        let mut old: Vec<Option<String>> = question.choices.drain(..).map(Some).collect();
+        question.choices = order
+            .iter()
+            .map(|&i| old[i].take().expect("each index appears once"))
+            .collect();
+    }
+}
+
+/// Reads an answer for `question`, asking again until it is a valid choice number.
+/// Returns `None` when the input runs out.
+fn ask(
+    question: &Question,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<Option<usize>> {
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        match line.trim().parse::<usize>() {
+            Ok(n) if (1..=question.choices.len()).contains(&n) => return Ok(Some(n - 1)),
+            _ => writeln!(
+                out,
+                "please answer with a number from 1 to {}",
+                question.choices.len()
+            )?,
+        }
+    }
+}
+
+/// Asks every question and returns how many were answered correctly.
+pub fn run(
+    questions: &[Question],
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<usize> {
+    let mut score = 0;
+    for (i, question) in questions.iter().enumerate() {
+        writeln!(
+            out,
+            "Question {}/{}: {}",
+            i + 1,
+            questions.len(),
+            question.prompt
+        )?;
+        for (j, choice) in question.choices.iter().enumerate() {
+            writeln!(out, "  {}) {}", j + 1, choice)?;
+        }
+        let Some(answer) = ask(question, input, out)? else {
+            writeln!(out)?;
+            writeln!(out, "quiz ended early")?;
+            break;
+        };
+        if answer == question.correct {
+            score += 1;
+            writeln!(out, "correct! {}", question.explanation)?;
+        } else {
+            let right = &question.choices[question.correct];
+            writeln!(
+                out,
+                "wrong, it's {}) {}. {}",
+                question.correct + 1,
+                right,
+                question.explanation
+            )?;
+        }
+        writeln!(out)?;
+    }
+    writeln!(out, "score: {}/{}", score, questions.len())?;
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn questions() -> Vec<Question> {
+        parse_questions(DEFAULT_QUESTIONS).unwrap()
+    }
+
+    fn play(answers: &str) -> (usize, String) {
+        let mut out = Vec::new();
+        let score = run(&questions(), &mut Cursor::new(answers), &mut out).unwrap();
+        (score, String::from_utf8(out).unwrap())
+    }
+
+    fn answers(pick: impl Fn(&Question) -> usize) -> String {
+        questions()
+            .iter()
+            .map(|q| format!("{}\n", pick(q)))
+            .collect()
+    }
+
+    fn question_json(choices: &str, correct: usize) -> String {
+        format!(
+            r#"{{"prompt": "p", "choices": [{}], "correct": {}, "explanation": "e"}}"#,
+            choices, correct
+        )
+    }
+
+    #[test]
+    fn embedded_defaults_parse() {
+        let questions = questions();
+        assert!(questions.len() >= 10);
+        assert_eq!(load_questions(None).unwrap(), questions);
+    }
+
+    #[test]
+    fn valid_file_loads() {
+        let dir = crate::raii::TempDir::new().unwrap();
+        let path = dir.path().join("questions.json");
+        let json = format!(
+            "[{}, {}]",
+            question_json(r#""a", "b""#, 1),
+            question_json(r#""a", "b", "c""#, 0)
+        );
+        fs::write(&path, json).unwrap();
+        let questions = load_questions(Some(&path)).unwrap();
+        assert_eq!(questions.len(), 2);
+        assert_eq!(questions[0].choices, ["a", "b"]);
+        assert_eq!(questions[1].correct, 0);
+    }
+
+    #[test]
+    fn out_of_range_correct_index_names_the_question() {
+        let json = format!(
+            "[{}, {}]",
+            question_json(r#""a", "b""#, 0),
+            question_json(r#""a", "b""#, 2)
+        );
+        let err = parse_questions(&json).unwrap_err();
+        assert!(matches!(err, QuizError::Invalid { index: 1, .. }));
+        assert_eq!(
+            err.to_string(),
+            "question 1: correct index 2 is out of range for 2 choices"
+        );
+    }
+
+    #[test]
+    fn too_few_or_too_many_choices() {
+        let one = format!("[{}]", question_json(r#""a""#, 0));
+        assert_eq!(
+            parse_questions(&one).unwrap_err().to_string(),
+            "question 0: needs 2 to 6 choices, has 1"
+        );
+        let seven = format!(
+            "[{}]",
+            question_json(r#""1", "2", "3", "4", "5", "6", "7""#, 0)
+        );
+        assert!(matches!(
+            parse_questions(&seven),
+            Err(QuizError::Invalid { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_json_is_an_error_not_a_panic() {
+        assert!(matches!(
+            parse_questions("[{\"prompt\": "),
+            Err(QuizError::Json(_))
+        ));
+        assert!(matches!(
+            parse_questions(r#"{"not": "a list"}"#),
+            Err(QuizError::Json(_))
+        ));
+        assert!(matches!(
+            load_questions(Some(Path::new("/definitely/not/here.json"))),
+            Err(QuizError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn all_correct_scores_full_marks() {
+        let (score, out) = play(&answers(|q| q.correct + 1));
+        assert_eq!(score, questions().len());
+        assert!(out.ends_with(&format!("score: {0}/{0}\n", questions().len())));
+        assert!(!out.contains("wrong"));
+    }
+
+    #[test]
+    fn wrong_answers_show_the_right_one() {
+        let (score, out) = play(&answers(|q| if q.correct == 0 { 2 } else { 1 }));
+        assert_eq!(score, 0);
+        assert!(out.contains("wrong, it's 2) only s2. The String moved"));
+        assert!(out.ends_with(&format!("score: 0/{}\n", questions().len())));
+    }
+
+    #[test]
+    fn invalid_input_is_asked_again() {
+        let input = format!("x\n0\n9\n\n2\n{}", &answers(|q| q.correct + 1)[2..]);
+        let (score, out) = play(&input);
+        assert_eq!(score, questions().len());
+        assert_eq!(
+            out.matches("please answer with a number from 1 to 4")
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn running_out_of_input_ends_the_quiz() {
+        let (score, out) = play("2\n");
+        assert_eq!(score, 1);
+        assert!(out.contains("quiz ended early"));
+        assert!(out.ends_with(&format!("score: 1/{}\n", questions().len())));
+    }
+
+    fn shuffled(seed: u64) -> Vec<Question> {
+        let mut questions = questions();
+        shuffle(&mut questions, &mut XorShift::new(seed));
+        questions
+    }
+
+    #[test]
+    fn same_seed_same_order() {
+        assert_eq!(shuffled(7), shuffled(7));
+    }
+
+    #[test]
+    fn different_seeds_usually_differ() {
+        let orders: Vec<Vec<Question>> = (1..=5).map(shuffled).collect();
+        let distinct = orders
+            .iter()
+            .enumerate()
+            .filter(|(i, order)| !orders[..*i].contains(order))
+            .count();
+        assert!(distinct > 1);
+        assert_ne!(shuffled(1), questions());
+    }
+
+    #[test]
+    fn shuffled_correct_index_still_scores() {
+        let original = questions();
+        let questions = shuffled(99);
+        for q in &questions {
+            let before = original.iter().find(|o| o.prompt == q.prompt).unwrap();
+            assert_eq!(q.choices[q.correct], before.choices[before.correct]);
+            let mut sorted = q.choices.clone();
+            sorted.sort();
+            let mut expected = before.choices.clone();
+            expected.sort();
+            assert_eq!(sorted, expected);
+        }
+        let answers: String = questions
+            .iter()
+            .map(|q| format!("{}\n", q.correct + 1))
+            .collect();
+        let score = run(&questions, &mut Cursor::new(answers), &mut Vec::new()).unwrap();
+        assert_eq!(score, questions.len());
+    }
+}
@@ -0,0 +1,157 @@
+// RAII: Resources Tied to an Owner -----------------------------
+// Rule 3 isn't only about memory. Any resource can be acquired in a constructor and released in
+// Drop, and the owner going out of scope (or a panic unwinding through it) cleans up for us
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::example::{Example, Rule, RunConfig};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A directory under the system temp dir that is removed, with its contents, when dropped.
+pub struct TempDir {
+    path: Option<PathBuf>, // None once keep() has taken it
+}
+
+impl TempDir {
+    /// Creates a fresh directory. A name that is already taken, left over from an earlier run
+    /// or made by someone else, is skipped rather than adopted: the guard only removes what it made.
+    pub fn new() -> io::Result<TempDir> {
+        TempDir::new_in(&std::env::temp_dir())
+    }
+
+    fn new_in(parent: &Path) -> io::Result<TempDir> {
+        loop {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let path = parent.join(format!("ownership-{}-{}", process::id(), id));
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(TempDir { path: Some(path) }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path
+            .as_deref()
+            .expect("only keep() takes the path, and it consumes the TempDir")
+    }
+
+    /// Disarms the cleanup and hands the path to the caller, who now owns the directory.
+    pub fn keep(mut self) -> PathBuf {
+        self.path
+            .take()
+            .expect("only keep() takes the path, and it consumes the TempDir")
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            let _ = fs::remove_dir_all(path); // Drop can't return errors, best effort
+        }
+    }
+}
+
+pub struct RaiiExample;
+
+impl Example for RaiiExample {
+    fn name(&self) -> &str {
+        "raii"
+    }
+
+    fn description(&self) -> &str {
+        "a TempDir that removes itself on drop"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("raii.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let path = {
+            let dir = TempDir::new()?;
+            fs::write(dir.path().join("notes.txt"), "temporary")?;
+            writeln!(out, "inside the scope: exists = {}", dir.path().exists())?;
+            dir.path().to_path_buf()
+        }; // dir is dropped here, taking the directory with it
+        writeln!(out, "after the scope:  exists = {}", path.exists())?;
+
+        let kept = TempDir::new()?.keep();
+        writeln!(out, "after keep():     exists = {}", kept.exists())?;
+        fs::remove_dir_all(kept) // we own it now, so we clean it up
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn directory_lives_as_long_as_the_guard() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().to_path_buf();
+        fs::write(path.join("file"), "data").unwrap();
+        assert!(path.is_dir());
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn each_guard_gets_its_own_directory() {
+        let a = TempDir::new().unwrap();
+        let b = TempDir::new().unwrap();
+        assert_ne!(a.path(), b.path());
+    }
+
+    #[test]
+    fn an_existing_directory_is_never_adopted() {
+        let parent = TempDir::new().unwrap();
+        let next = NEXT_ID.load(Ordering::Relaxed);
+        let taken: Vec<PathBuf> = (next..next + 8)
+            .map(|id| {
+                parent
+                    .path()
+                    .join(format!("ownership-{}-{}", process::id(), id))
+            })
+            .collect();
+        for path in &taken {
+            fs::create_dir(path).unwrap();
+            fs::write(path.join("not-ours"), "keep me").unwrap();
+        }
+        let dir = TempDir::new_in(parent.path()).unwrap();
+        assert!(!taken.iter().any(|path| path == dir.path()));
+        drop(dir);
+        for path in &taken {
+            assert!(path.join("not-ours").exists()); // skipped, so never removed
+        }
+    }
+
+    #[test]
+    fn keep_disarms_the_cleanup() {
+        let path = TempDir::new().unwrap().keep();
+        assert!(path.is_dir());
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn cleanup_runs_during_a_panic() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().to_path_buf();
+        let result = panic::catch_unwind(move || {
+            let _owner = dir;
+            panic!("unwinding through the owner");
+        });
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,99 @@
+// Clone on Write: Rc::make_mut ---------------------------------
+// Rc handles share one value and can't change it. `Rc::make_mut` is the way out: it hands back a
+// `&mut` straight away when this handle is the only owner, and otherwise clones the value first so
+// the other handles keep seeing the old one
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Pushes `value` through `shared`, returning whether the Vec had to be deep-copied first.
+///
+/// make_mut clones exactly when another strong handle exists; weak handles are only detached.
+pub fn append_cow(shared: &mut Rc<Vec<i32>>, value: i32) -> bool {
+    let copies = Rc::strong_count(shared) > 1;
+    Rc::make_mut(shared).push(value);
+    copies
+}
+
+pub struct RcCowExample;
+
+impl Example for RcCowExample {
+    fn name(&self) -> &str {
+        "rc-cow"
+    }
+
+    fn description(&self) -> &str {
+        "Rc::make_mut clones only when the value is shared"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("rc_cow.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut a = Rc::new(vec![1, 2, 3]);
+        let b = Rc::clone(&a);
+        writeln!(
+            out,
+            "a and b share {:?}, strong_count {}, ptr_eq {}",
+            a,
+            Rc::strong_count(&a),
+            Rc::ptr_eq(&a, &b)
+        )?;
+        let copied = append_cow(&mut a, 4);
+        writeln!(
+            out,
+            "append_cow(a, 4) copied: {}, a = {:?}, b = {:?}, ptr_eq {}",
+            copied,
+            a,
+            b,
+            Rc::ptr_eq(&a, &b)
+        )?;
+        let copied = append_cow(&mut a, 5);
+        writeln!(
+            out,
+            "a is the only owner of its copy now, append_cow(a, 5) copied: {}, a = {:?}",
+            copied, a
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sole_owner_mutates_in_place() {
+        let mut only = Rc::new(vec![1]);
+        let before = Rc::as_ptr(&only);
+        assert!(!append_cow(&mut only, 2));
+        assert_eq!(*only, [1, 2]);
+        assert_eq!(Rc::as_ptr(&only), before);
+    }
+
+    #[test]
+    fn shared_value_is_copied_and_the_other_handle_unchanged() {
+        let mut a = Rc::new(vec![1, 2]);
+        let b = Rc::clone(&a);
+        assert!(append_cow(&mut a, 3));
+        assert_eq!(*a, [1, 2, 3]);
+        assert_eq!(*b, [1, 2]);
+        assert!(!Rc::ptr_eq(&a, &b));
+        assert_eq!(Rc::strong_count(&a), 1);
+        assert_eq!(Rc::strong_count(&b), 1);
+    }
+
+    #[test]
+    fn weak_handles_alone_cause_no_copy() {
+        let mut a = Rc::new(vec![1]);
+        let weak = Rc::downgrade(&a);
+        assert!(!append_cow(&mut a, 2));
+        assert_eq!(*a, [1, 2]);
+        assert!(weak.upgrade().is_none());
+    }
+}
@@ -0,0 +1,92 @@
+// Shared Ownership: Rc<T> ----------------------------------------
+// Rule 2 says there is one owner at a time. Rc (reference counted) is the escape hatch: every Rc
+// handle co-owns the value, and the value is dropped only when the last handle goes out of scope
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, Tracer};
+
+/// Clones and drops `Rc` handles to one value, printing `Rc::strong_count` after every step.
+///
+/// The shared value is only reachable through `&`, so it can't be mutated through an `Rc`
+/// without interior mutability:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// let shared = Rc::new(String::from("hello"));
+/// shared.push_str(", world"); // error: cannot borrow data in an `Rc` as mutable
+/// ```
+pub fn shared_ownership(out: &mut impl Write) -> io::Result<()> {
+    let log = new_log();
+    // the Tracer owns the String label and logs when it's dropped, so the free is observable
+    let a = Rc::new(Tracer::new("hello", &log));
+    writeln!(out, "a created:   strong_count = {}", Rc::strong_count(&a))?;
+    let b = Rc::clone(&a); // Rc::clone copies the pointer and bumps the count, not a deep copy
+    writeln!(out, "b = a.clone: strong_count = {}", Rc::strong_count(&a))?;
+    let c = Rc::clone(&a);
+    writeln!(out, "c = a.clone: strong_count = {}", Rc::strong_count(&a))?;
+
+    drop(c);
+    writeln!(out, "c dropped:   strong_count = {}", Rc::strong_count(&a))?;
+    drop(b);
+    writeln!(out, "b dropped:   strong_count = {}", Rc::strong_count(&a))?;
+    writeln!(out, "value still alive: {}", log.borrow().len() == 1)?;
+
+    drop(a); // last owner gone, the Tracer (and its String) is freed now
+    let events = log.borrow().join(", ");
+    writeln!(out, "a dropped:   {}", events)
+}
+
+pub struct RcExample;
+
+impl Example for RcExample {
+    fn name(&self) -> &str {
+        "rc"
+    }
+
+    fn description(&self) -> &str {
+        "shared ownership with reference counting"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("rc_demo.rs")
+    }
+
+    fn run(&self, mut out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        shared_ownership(&mut out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output() -> String {
+        let mut out = Vec::new();
+        shared_ownership(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn strong_count_goes_up_with_clones_and_down_with_drops() {
+        let counts: Vec<usize> = output()
+            .lines()
+            .filter_map(|l| l.split("strong_count = ").nth(1))
+            .map(|n| n.parse().unwrap())
+            .collect();
+        assert_eq!(counts, [1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn value_is_only_freed_with_the_last_handle() {
+        let text = output();
+        assert!(text.contains("value still alive: true"));
+        assert!(text.ends_with("a dropped:   new: hello, drop: hello\n"));
+    }
+}
@@ -0,0 +1,114 @@
+// Interior Mutability: RefCell<T> --------------------------------
+// RefCell moves the borrow rules from compile time to run time: any number of `borrow()`s or
+// exactly one `borrow_mut()` at a time, and breaking that rule panics instead of failing to compile
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Same as `borrowing::mutable::change`, but through a shared `&RefCell` instead of `&mut String`.
+pub fn change(some_string: &RefCell<String>) {
+    some_string.borrow_mut().push_str(", world");
+} // the RefMut guard is dropped here, which ends the mutable borrow
+
+/// Reads, then writes, then reads again; every borrow ends before the next one starts.
+pub fn sequential_borrows(cell: &RefCell<String>) -> usize {
+    let before = cell.borrow().len(); // immutable borrow, released at the end of the statement
+    change(cell); // mutable borrow, released when change returns
+    let after = cell.borrow().len();
+    after - before
+}
+
+/// Takes a mutable borrow while an immutable one is still alive.
+///
+/// # Panics
+///
+/// Always, with `already borrowed`: this is the runtime version of the compile error in the
+/// Mutable References section.
+pub fn overlapping_borrows(cell: &RefCell<String>) {
+    let reader = cell.borrow();
+    let mut writer = cell.borrow_mut(); // panics, `reader` is still alive
+    writer.push_str(&reader);
+}
+
+pub struct RefCellExample;
+
+impl Example for RefCellExample {
+    fn name(&self) -> &str {
+        "refcell"
+    }
+
+    fn description(&self) -> &str {
+        "borrow rules checked at run time"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("refcell_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut compile_time = String::from("hello");
+        crate::borrowing::mutable::change(&mut compile_time);
+        writeln!(out, "change(&mut String):      {}", compile_time)?;
+
+        let runtime = RefCell::new(String::from("hello"));
+        change(&runtime);
+        writeln!(out, "change(&RefCell<String>): {}", runtime.borrow())?;
+
+        let grown = sequential_borrows(&runtime);
+        writeln!(out, "sequential borrows grew it by {} bytes", grown)?;
+
+        // try_borrow_mut reports the conflict as an error instead of panicking like borrow_mut
+        let reader = runtime.borrow();
+        match runtime.try_borrow_mut() {
+            Ok(_) => writeln!(out, "overlapping borrow_mut: allowed?!")?,
+            Err(e) => writeln!(out, "overlapping borrow_mut: {}", e)?,
+        }
+        drop(reader);
+        let free = runtime.try_borrow_mut().is_ok();
+        writeln!(out, "after the reader is dropped: {}", free)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn change_works_through_a_shared_reference() {
+        let cell = RefCell::new(String::from("hello"));
+        let alias = &cell;
+        change(alias);
+        change(&cell);
+        assert_eq!(*cell.borrow(), "hello, world, world");
+    }
+
+    #[test]
+    fn sequential_borrows_are_fine() {
+        let cell = RefCell::new(String::from("hello"));
+        assert_eq!(sequential_borrows(&cell), ", world".len());
+    }
+
+    #[test]
+    fn overlapping_borrows_panic_at_runtime() {
+        let result = panic::catch_unwind(|| {
+            let cell = RefCell::new(String::from("hello"));
+            overlapping_borrows(&cell);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn several_immutable_borrows_may_overlap() {
+        let cell = RefCell::new(String::from("hello"));
+        let r1 = cell.borrow();
+        let r2 = cell.borrow();
+        assert_eq!(*r1, *r2);
+        assert!(cell.try_borrow_mut().is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// RunReport: how long each example took
+// Run-all mode times every `run()` and ends with a table of the timings; formatting the table
+// is a pure function of the report, so it can be tested without running anything
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub name: String,
+    pub duration: Duration,
+    /// Lines the example wrote, its banner not included.
+    pub lines: usize,
+}
+
+/// Timings in the order the examples ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunReport {
+    pub timings: Vec<Timing>,
+}
+
+impl RunReport {
+    pub fn new() -> RunReport {
+        RunReport::default()
+    }
+
+    pub fn record(&mut self, name: &str, duration: Duration, lines: usize) {
+        self.timings.push(Timing {
+            name: name.to_string(),
+            duration,
+            lines,
+        });
+    }
+
+    /// The example that took the longest, the first one on a tie.
+    pub fn slowest(&self) -> Option<&Timing> {
+        self.timings.iter().reduce(|slowest, t| {
+            if t.duration > slowest.duration {
+                t
+            } else {
+                slowest
+            }
+        })
+    }
+
+    /// The time spent in the examples themselves.
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(|t| t.duration).sum()
+    }
+}
+
+/// One row per example, names left-aligned and numbers right-aligned under a header.
+pub fn table(report: &RunReport) -> String {
+    let rows: Vec<(&str, String, String)> = report
+        .timings
+        .iter()
+        .map(|t| {
+            (
+                t.name.as_str(),
+                format!("{:.2?}", t.duration),
+                t.lines.to_string(),
+            )
+        })
+        .collect();
+    let name_width = rows
+        .iter()
+        .map(|r| r.0.len())
+        .max()
+        .unwrap_or(0)
+        .max("example".len());
+    let time_width = rows
+        .iter()
+        .map(|r| r.1.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("time".len());
+    let mut table = format!(
+        "{:<nw$}  {:>tw$}  lines\n",
+        "example",
+        "time",
+        nw = name_width,
+        tw = time_width
+    );
+    for (name, time, lines) in &rows {
+        table.push_str(&format!(
+            "{:<nw$}  {:>tw$}  {:>5}\n",
+            name,
+            time,
+            lines,
+            nw = name_width,
+            tw = time_width
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(rows: &[(&str, u64, usize)]) -> RunReport {
+        let mut report = RunReport::new();
+        for &(name, micros, lines) in rows {
+            report.record(name, Duration::from_micros(micros), lines);
+        }
+        report
+    }
+
+    #[test]
+    fn slowest_and_total() {
+        let report = report(&[("a", 5, 1), ("b", 20, 2), ("c", 20, 3)]);
+        assert_eq!(report.slowest().unwrap().name, "b");
+        assert_eq!(report.total(), Duration::from_micros(45));
+        assert_eq!(RunReport::new().slowest(), None);
+    }
+
+    #[test]
+    fn table_aligns_short_and_long_names() {
+        let table = table(&report(&[("a", 5, 1), ("mutable-references", 1500, 12)]));
+        assert_eq!(
+            table,
+            "example               time  lines\n\
+             a                   5.00µs      1\n\
+             mutable-references  1.50ms     12\n"
+        );
+    }
+
+    #[test]
+    fn table_header_sets_the_minimum_width() {
+        assert_eq!(
+            table(&report(&[("x", 0, 0)])),
+            "example    time  lines\nx        0.00ns      0\n"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// XorShift: a tiny deterministic random number generator
+// Good enough to shuffle quiz questions, and the same seed always gives the same sequence. Not
+// for anything that needs to be unpredictable
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Marsaglia's xorshift64, which needs a non-zero state.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> XorShift {
+        // zero would stay zero forever, map it to an arbitrary odd constant
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        XorShift { state }
+    }
+
+    /// A seed taken from the clock, for when the caller doesn't care which one.
+    pub fn clock_seed() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// A number in `0..n`, `n` must not be zero. The modulo bias is negligible for small `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Fisher-Yates: every permutation of `items` is equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let a: Vec<u64> = {
+            let mut rng = XorShift::new(42);
+            (0..5).map(|_| rng.next_u64()).collect()
+        };
+        let mut rng = XorShift::new(42);
+        assert!(a.iter().all(|&x| x == rng.next_u64()));
+        assert_ne!(XorShift::new(43).next_u64(), a[0]);
+    }
+
+    #[test]
+    fn zero_seed_still_moves() {
+        let mut rng = XorShift::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn shuffle_is_a_permutation() {
+        let mut items: Vec<u32> = (0..20).collect();
+        XorShift::new(7).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn below_stays_in_range() {
+        let mut rng = XorShift::new(1);
+        assert!((0..1000).all(|_| rng.below(6) < 6));
+    }
+}
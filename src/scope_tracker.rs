@@ -0,0 +1,194 @@
+// ScopeTracker: records a timeline of values being created, moved and dropped
+use std::fmt;
+
+use crate::output::Style;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Created(String),
+    Moved { from: String, to: String },
+    Dropped(String),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Created(name) => write!(f, "created {}", name),
+            Event::Moved { from, to } => write!(f, "moved {} -> {}", from, to),
+            Event::Dropped(name) => write!(f, "dropped {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackError {
+    /// The value was never created, or has already been moved out or dropped.
+    NotLive(String),
+}
+
+impl fmt::Display for TrackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackError::NotLive(name) => write!(f, "`{}` is not live", name),
+        }
+    }
+}
+
+impl std::error::Error for TrackError {}
+
+/// Keeps the list of live bindings in creation order, plus a snapshot of it after every event.
+#[derive(Debug, Default)]
+pub struct ScopeTracker {
+    live: Vec<String>,
+    steps: Vec<(Event, Vec<String>)>,
+}
+
+impl ScopeTracker {
+    pub fn new() -> ScopeTracker {
+        ScopeTracker::default()
+    }
+
+    pub fn created(&mut self, name: &str) {
+        self.live.push(name.to_string());
+        self.record(Event::Created(name.to_string()));
+    }
+
+    /// Moves `from` into `to`; fails if `from` was already moved or dropped.
+    pub fn moved(&mut self, from: &str, to: &str) -> Result<(), TrackError> {
+        let i = self.position(from)?;
+        self.live.remove(i);
+        self.live.push(to.to_string());
+        self.record(Event::Moved {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Drops `name`; fails if it is not live, that would be a double free.
+    pub fn dropped(&mut self, name: &str) -> Result<(), TrackError> {
+        let i = self.position(name)?;
+        self.live.remove(i);
+        self.record(Event::Dropped(name.to_string()));
+        Ok(())
+    }
+
+    pub fn live(&self) -> &[String] {
+        &self.live
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.steps.iter().map(|(event, _)| event)
+    }
+
+    /// One line per event with the bindings that are live right after it.
+    pub fn report(&self) -> String {
+        self.report_styled(&Style::PLAIN)
+    }
+
+    /// [`ScopeTracker::report`] with moves highlighted and the live bindings colored by `style`.
+    pub fn report_styled(&self, style: &Style) -> String {
+        let width = self
+            .steps
+            .iter()
+            .map(|(event, _)| event.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let mut report = String::new();
+        for (i, (event, live)) in self.steps.iter().enumerate() {
+            let live = if live.is_empty() {
+                "-".to_string()
+            } else {
+                let names: Vec<String> = live.iter().map(|name| style.live(name)).collect();
+                names.join(", ")
+            };
+            // pad before styling, escape codes have no width on screen
+            let padded = format!("{:width$}", event.to_string(), width = width);
+            let shown = match event {
+                Event::Moved { .. } => style.moved(&padded),
+                _ => padded,
+            };
+            report.push_str(&format!("{:>2}. {}  live: {}\n", i + 1, shown, live));
+        }
+        report
+    }
+
+    fn position(&self, name: &str) -> Result<usize, TrackError> {
+        self.live
+            .iter()
+            .position(|n| n == name)
+            .ok_or_else(|| TrackError::NotLive(name.to_string()))
+    }
+
+    fn record(&mut self, event: Event) {
+        self.steps.push((event, self.live.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_leaves_only_the_new_owner_live() {
+        let mut tracker = ScopeTracker::new();
+        tracker.created("s1");
+        tracker.moved("s1", "s2").unwrap();
+        assert_eq!(tracker.live(), ["s2"]);
+    }
+
+    #[test]
+    fn moving_twice_is_an_error() {
+        let mut tracker = ScopeTracker::new();
+        tracker.created("s1");
+        tracker.moved("s1", "s2").unwrap();
+        assert_eq!(
+            tracker.moved("s1", "s3"),
+            Err(TrackError::NotLive("s1".to_string()))
+        );
+        assert_eq!(tracker.live(), ["s2"]);
+        assert_eq!(tracker.events().count(), 2);
+    }
+
+    #[test]
+    fn dropping_a_value_that_is_not_live_is_an_error() {
+        let mut tracker = ScopeTracker::new();
+        tracker.created("s1");
+        tracker.dropped("s1").unwrap();
+        assert!(tracker.dropped("s1").is_err());
+        assert!(tracker.dropped("never").is_err());
+        assert!(tracker.live().is_empty());
+    }
+
+    #[test]
+    fn report_lists_every_step_with_the_live_values() {
+        let mut tracker = ScopeTracker::new();
+        tracker.created("s1");
+        tracker.moved("s1", "s2").unwrap();
+        tracker.created("x");
+        tracker.dropped("s2").unwrap();
+        tracker.dropped("x").unwrap();
+        assert_eq!(
+            tracker.report(),
+            " 1. created s1      live: s1\n 2. moved s1 -> s2  live: s2\n 3. created x       live: s2, x\n 4. dropped s2      live: x\n 5. dropped x       live: -\n"
+        );
+    }
+
+    #[test]
+    fn styled_report_colors_moves_and_live_bindings() {
+        let mut tracker = ScopeTracker::new();
+        tracker.created("s1");
+        tracker.moved("s1", "s2").unwrap();
+        let report = tracker.report_styled(&Style::new(true));
+        assert_eq!(
+            report,
+            " 1. created s1      live: \x1b[32ms1\x1b[0m\n 2. \x1b[31mmoved s1 -> s2\x1b[0m  live: \x1b[32ms2\x1b[0m\n"
+        );
+        assert_eq!(tracker.report_styled(&Style::PLAIN), tracker.report());
+    }
+
+    #[test]
+    fn empty_tracker_reports_nothing() {
+        assert_eq!(ScopeTracker::new().report(), "");
+    }
+}
@@ -0,0 +1,192 @@
+// Self-Referential Structs -------------------------------------
+// A struct can't hold a String and a &str into that same String: moving the struct would move the
+// String out from under the borrow. Keep a byte range and slice on demand, or split the owner and
+// the borrower into two structs
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The source has no `name=` field.
+    MissingName,
+    /// The `name=` field is there but has no value.
+    EmptyName,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingName => write!(f, "no `name=` field"),
+            ParseError::EmptyName => write!(f, "`name=` has no value"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The byte range of the value of the first `name=` field in a line of space-separated fields.
+fn find_name(source: &str) -> Result<Range<usize>, ParseError> {
+    let mut offset = 0;
+    for field in source.split(' ') {
+        if let Some(value) = field.strip_prefix("name=") {
+            if value.is_empty() {
+                return Err(ParseError::EmptyName);
+            }
+            let start = offset + "name=".len();
+            return Ok(start..start + value.len());
+        }
+        offset += field.len() + 1;
+    }
+    Err(ParseError::MissingName)
+}
+
+/// Owns its source and remembers where the name is, instead of borrowing it.
+///
+/// Borrowing it would need a lifetime for a borrow of the struct's own field, and the String
+/// can't move into the struct while the slice still borrows it:
+///
+/// ```compile_fail
+/// struct SelfRef<'a> {
+///     source: String,
+///     name: &'a str,
+/// }
+///
+/// fn parse<'a>(source: String) -> SelfRef<'a> {
+///     let name = &source[5..];
+///     SelfRef { source, name } // error: cannot move out of `source` because it is borrowed
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parsed {
+    source: String,
+    name_range: Range<usize>,
+}
+
+impl Parsed {
+    pub fn new(source: String) -> Result<Parsed, ParseError> {
+        let name_range = find_name(&source)?;
+        Ok(Parsed { source, name_range })
+    }
+
+    /// Slices the name out of the source, the range was checked when it was found.
+    pub fn name(&self) -> &str {
+        &self.source[self.name_range.clone()]
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+/// The owning half of the two-struct alternative.
+pub struct Source {
+    pub text: String,
+}
+
+/// The borrowing half: it can hold real `&str`s because the Source lives outside of it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fields<'a> {
+    pub name: &'a str,
+}
+
+impl Source {
+    pub fn fields(&self) -> Result<Fields<'_>, ParseError> {
+        let range = find_name(&self.text)?;
+        Ok(Fields {
+            name: &self.text[range],
+        })
+    }
+}
+
+pub struct SelfRefExample;
+
+impl Example for SelfRefExample {
+    fn name(&self) -> &str {
+        "self-ref"
+    }
+
+    fn description(&self) -> &str {
+        "a struct can't borrow from its own String"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("self_ref.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let parsed = Parsed::new(String::from("id=7 name=ana role=admin")).expect("has a name");
+        writeln!(
+            out,
+            "Parsed keeps {:?} and the range {:?}",
+            parsed.source(),
+            parsed.name_range
+        )?;
+        let moved = parsed; // moving is fine, a range stays valid wherever the String goes
+        writeln!(out, "name() after a move = {}", moved.name())?;
+        let source = Source {
+            text: String::from("name=bo id=8"),
+        };
+        let fields = source.fields().expect("has a name");
+        writeln!(out, "Fields borrows from a separate Source: {:?}", fields)?;
+        match Parsed::new(String::from("id=9")) {
+            Ok(parsed) => writeln!(out, "unexpected name {}", parsed.name()),
+            Err(e) => writeln!(out, "Parsed::new(\"id=9\") -> error: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name_of(source: &str) -> Result<String, ParseError> {
+        Parsed::new(source.to_string()).map(|p| p.name().to_string())
+    }
+
+    #[test]
+    fn name_at_the_start_and_the_end() {
+        assert_eq!(name_of("name=ana id=7").unwrap(), "ana");
+        assert_eq!(name_of("id=7 name=ana").unwrap(), "ana");
+        assert_eq!(name_of("name=ana").unwrap(), "ana");
+    }
+
+    #[test]
+    fn name_in_the_middle_and_the_first_wins() {
+        assert_eq!(name_of("id=7 name=bo role=x name=cy").unwrap(), "bo");
+    }
+
+    #[test]
+    fn missing_or_empty_name_is_an_error() {
+        assert_eq!(name_of("id=7"), Err(ParseError::MissingName));
+        assert_eq!(name_of(""), Err(ParseError::MissingName));
+        assert_eq!(name_of("username=ana"), Err(ParseError::MissingName));
+        assert_eq!(name_of("id=7 name="), Err(ParseError::EmptyName));
+    }
+
+    #[test]
+    fn the_range_survives_a_move() {
+        let parsed = Parsed::new(String::from("id=1 name=dee")).unwrap();
+        let boxed = Box::new(parsed);
+        assert_eq!(boxed.name(), "dee");
+    }
+
+    #[test]
+    fn fields_borrow_from_the_source() {
+        let source = Source {
+            text: String::from("name=eve"),
+        };
+        let fields = source.fields().unwrap();
+        assert_eq!(fields, Fields { name: "eve" });
+        assert!(std::ptr::eq(
+            fields.name.as_ptr(),
+            source.text[5..].as_ptr()
+        ));
+    }
+}
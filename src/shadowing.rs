@@ -0,0 +1,170 @@
+// Shadowing vs Mutation ----------------------------------------
+// `let mut s = ...; s = ...;` assigns a new value of the same type to the same variable, the old value
+// is dropped right there. `let s = ...; let s = ...;` declares a new variable that happens to have the
+// same name, so it may have a different type, and the old value is only dropped early if it was moved
+use std::fmt;
+use std::io::{self, Write};
+use std::num::ParseIntError;
+
+use crate::drop_order;
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{mark, new_log, DropLog, Tracer};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoubleError {
+    Parse(ParseIntError),
+    /// The number parsed, but twice it doesn't fit in an i64.
+    Overflow,
+}
+
+impl fmt::Display for DoubleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoubleError::Parse(e) => write!(f, "{}", e),
+            DoubleError::Overflow => write!(f, "doubling overflows an i64"),
+        }
+    }
+}
+
+impl std::error::Error for DoubleError {}
+
+impl From<ParseIntError> for DoubleError {
+    fn from(e: ParseIntError) -> Self {
+        DoubleError::Parse(e)
+    }
+}
+
+/// Parses a number and doubles it, re-using the name `input` for each step.
+///
+/// A `mut` binding can't change type the way shadowing can:
+///
+/// ```compile_fail
+/// let mut input = String::from(" 21 ");
+/// input = input.trim().len(); // error: mismatched types, expected `String`, found `usize`
+/// ```
+pub fn parse_and_double(input: String) -> Result<i64, DoubleError> {
+    let input = input.trim(); // &str borrowed from the shadowed String, which is still alive
+    let input: i64 = input.parse()?;
+    let input = input.checked_mul(2).ok_or(DoubleError::Overflow)?;
+    Ok(input)
+}
+
+fn rename(t: Tracer, log: &DropLog) -> Tracer {
+    Tracer::new(&format!("{} renamed", t.label()), log)
+} // t, the value moved in, is dropped here
+
+/// Shadowing with a value built from the old one by moving it: the old one is gone before the
+/// new binding exists, because it was moved into `rename` and dropped there.
+pub fn shadow_by_moving(log: &DropLog) {
+    let t = Tracer::new("first", log);
+    let t = rename(t, log);
+    mark(log, &format!("end of scope, {} is visible", t.label()));
+}
+
+type Demo = fn(&DropLog);
+
+pub struct ShadowingExample;
+
+impl Example for ShadowingExample {
+    fn name(&self) -> &str {
+        "shadowing"
+    }
+
+    fn description(&self) -> &str {
+        "shadowing a binding vs mutating it"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("shadowing.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let s = String::from("  hello  ");
+        let s = s.trim();
+        let s = s.len(); // the same name, now a usize
+        writeln!(out, "shadowed String -> &str -> usize: {}", s)?;
+        writeln!(
+            out,
+            "parse_and_double(\" 21 \") = {:?}",
+            parse_and_double(String::from(" 21 "))
+        )?;
+
+        let demos: [(&str, Demo); 3] = [
+            ("mutation", drop_order::reassignment),
+            ("shadowing", drop_order::shadowing),
+            ("shadowing by moving", shadow_by_moving),
+        ];
+        for (title, demo) in demos {
+            let log = new_log();
+            demo(&log);
+            let events = log.borrow();
+            writeln!(out, "{}: {}", title, events.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_double_valid_numbers() {
+        assert_eq!(parse_and_double(String::from("21")), Ok(42));
+        assert_eq!(parse_and_double(String::from("-5")), Ok(-10));
+        assert_eq!(parse_and_double(String::from("0")), Ok(0));
+    }
+
+    #[test]
+    fn parse_and_double_trims_whitespace() {
+        assert_eq!(parse_and_double(String::from("  7\n")), Ok(14));
+    }
+
+    #[test]
+    fn parse_and_double_invalid_input() {
+        assert!(parse_and_double(String::from("seven")).is_err());
+        assert!(parse_and_double(String::from("")).is_err());
+        assert!(parse_and_double(String::from("1 2")).is_err());
+        assert!(matches!(
+            parse_and_double(String::from("seven")),
+            Err(DoubleError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn parse_and_double_overflow() {
+        assert_eq!(
+            parse_and_double(i64::MAX.to_string()),
+            Err(DoubleError::Overflow)
+        );
+        assert_eq!(
+            parse_and_double(i64::MIN.to_string()),
+            Err(DoubleError::Overflow)
+        );
+        assert_eq!(
+            parse_and_double((i64::MAX / 2).to_string()),
+            Ok(i64::MAX - 1)
+        );
+        assert_eq!(parse_and_double((i64::MIN / 2).to_string()), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn shadowing_by_moving_drops_the_old_value_first() {
+        let log = new_log();
+        shadow_by_moving(&log);
+        assert_eq!(
+            *log.borrow(),
+            [
+                "new: first",
+                "new: first renamed",
+                "drop: first",
+                "end of scope, first renamed is visible",
+                "drop: first renamed",
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+// String, Rc<str> and Arc<str> ---------------------------------
+// Handing the same immutable text to many readers: cloning a String copies the text for every one
+// of them, while cloning an Rc<str> or Arc<str> copies a pointer and bumps a count. Rc stays on one
+// thread, Arc's atomic count lets the handles go to other threads
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// `n` Strings, each with its own copy of `text`.
+pub fn fan_out_string(text: &str, n: usize) -> Vec<String> {
+    let original = String::from(text);
+    vec![original; n]
+}
+
+/// `n` handles to one shared copy of `text`.
+pub fn fan_out_rc(text: &str, n: usize) -> Vec<Rc<str>> {
+    let shared: Rc<str> = Rc::from(text);
+    vec![shared; n]
+}
+
+/// Like [`fan_out_rc`], but the handles can be sent to other threads.
+pub fn fan_out_arc(text: &str, n: usize) -> Vec<Arc<str>> {
+    let shared: Arc<str> = Arc::from(text);
+    vec![shared; n]
+}
+
+/// Moves every handle into a thread of its own, returning the length each thread read.
+pub fn read_in_threads(handles: Vec<Arc<str>>) -> Vec<usize> {
+    let threads: Vec<_> = handles
+        .into_iter()
+        .map(|text| thread::spawn(move || text.len()))
+        .collect();
+    threads
+        .into_iter()
+        .map(|t| t.join().expect("reader thread panicked"))
+        .collect()
+}
+
+/// Allocations made by `f` on this thread, when the `count-allocs` feature is on.
+#[cfg(feature = "count-allocs")]
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    let (result, stats) = crate::alloc_count::measure(f);
+    (result, Some(stats.allocations))
+}
+
+#[cfg(not(feature = "count-allocs"))]
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    (f(), None)
+}
+
+fn allocated(count: Option<usize>) -> String {
+    match count {
+        Some(n) => format!(", {} allocations", n),
+        None => String::new(),
+    }
+}
+
+pub struct SharedStringsExample;
+
+impl Example for SharedStringsExample {
+    fn name(&self) -> &str {
+        "shared-strings"
+    }
+
+    fn description(&self) -> &str {
+        "many readers of one text: String, Rc<str> or Arc<str>"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("shared_strings.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let text = "the same immutable text";
+        let n = 4;
+        let (strings, allocs) = allocations(|| fan_out_string(text, n));
+        writeln!(
+            out,
+            "String:   {} copies of {} bytes{}",
+            strings.len(),
+            text.len(),
+            allocated(allocs)
+        )?;
+        let (rcs, allocs) = allocations(|| fan_out_rc(text, n));
+        writeln!(
+            out,
+            "Rc<str>:  {} handles, strong_count {}{}",
+            rcs.len(),
+            Rc::strong_count(&rcs[0]),
+            allocated(allocs)
+        )?;
+        let (arcs, allocs) = allocations(|| fan_out_arc(text, n));
+        writeln!(
+            out,
+            "Arc<str>: {} handles, strong_count {}{}",
+            arcs.len(),
+            Arc::strong_count(&arcs[0]),
+            allocated(allocs)
+        )?;
+        if allocs.is_none() {
+            writeln!(
+                out,
+                "(build with --features count-allocs to see the allocations)"
+            )?;
+        }
+        writeln!(out, "{} threads each read {:?}", n, read_in_threads(arcs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_strategy_gives_equal_text() {
+        let text = "hello";
+        assert!(fan_out_string(text, 3).iter().all(|s| s == text));
+        assert!(fan_out_rc(text, 3).iter().all(|s| &**s == text));
+        assert!(fan_out_arc(text, 3).iter().all(|s| &**s == text));
+        assert!(fan_out_rc(text, 0).is_empty());
+    }
+
+    #[test]
+    fn strings_are_separate_copies() {
+        let strings = fan_out_string("hello", 2);
+        assert_ne!(strings[0].as_ptr(), strings[1].as_ptr());
+    }
+
+    #[test]
+    fn rc_handles_share_one_allocation() {
+        let rcs = fan_out_rc("hello", 3);
+        assert!(rcs.iter().all(|rc| Rc::ptr_eq(rc, &rcs[0])));
+        assert_eq!(Rc::strong_count(&rcs[0]), 3);
+    }
+
+    #[test]
+    fn arc_handles_work_from_spawned_threads() {
+        let arcs = fan_out_arc("hello", 3);
+        assert!(arcs.iter().all(|arc| Arc::ptr_eq(arc, &arcs[0])));
+        let keep = Arc::clone(&arcs[0]);
+        assert_eq!(read_in_threads(arcs), [5, 5, 5]);
+        // every thread dropped its handle when it finished
+        assert_eq!(Arc::strong_count(&keep), 1);
+    }
+
+    #[cfg(feature = "count-allocs")]
+    #[test]
+    fn shared_handles_allocate_the_text_once() {
+        let (_, strings) = crate::alloc_count::measure(|| fan_out_string("hello", 5));
+        let (_, rcs) = crate::alloc_count::measure(|| fan_out_rc("hello", 5));
+        // the Vec, plus the text once per String or once for all the Rcs
+        assert_eq!(strings.allocations, 1 + 5);
+        assert_eq!(rcs.allocations, 1 + 1);
+    }
+}
@@ -0,0 +1,235 @@
+// Slice Type ---------------------------------------------------
+// A slice is a reference to a part of a String
+// Slices do not have ownership
+// include the starting index and exclude the ending index
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Returns the first whitespace-delimited word of `s`, skipping leading whitespace.
+///
+/// The returned slice borrows from `s`, so it can't outlive it:
+///
+/// ```
+/// use ownership::slices::first_word;
+///
+/// let s = String::from("hello world");
+/// let word = first_word(&s);
+/// assert_eq!(word, "hello");
+/// ```
+///
+/// While `word` is alive `s` is borrowed immutably, so `clear` (which needs `&mut s`) is rejected:
+///
+/// ```compile_fail
+/// use ownership::slices::first_word;
+///
+/// let mut s = String::from("hello world");
+/// let word = first_word(&s);
+/// s.clear(); // error: cannot borrow `s` as mutable because it is also borrowed as immutable
+/// println!("the first word is: {}", word);
+/// ```
+pub fn first_word(s: &str) -> &str {
+    let s = s.trim_start();
+    // char_indices yields byte offsets, so slicing at them never splits a multi-byte character
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() {
+            return &s[..i]; // return a slice of the original string
+        }
+    }
+    s // no whitespace, the whole string is the first word
+}
+
+/// Iterator over the whitespace-delimited words of a string slice, created by [`words`].
+///
+/// Every item borrows from the same input as the iterator itself, hence the shared `'a`.
+pub struct Words<'a> {
+    source: &'a str,
+    offset: usize, // byte offset of the first character not yet looked at
+}
+
+/// Returns an iterator over the words of `s`.
+///
+/// ```
+/// use ownership::slices::words;
+///
+/// let s = String::from("  one two  three ");
+/// let all: Vec<&str> = words(&s).collect();
+/// assert_eq!(all, ["one", "two", "three"]);
+/// ```
+///
+/// The source can't be mutated while the iterator (or any word it yielded) is alive:
+///
+/// ```compile_fail
+/// use ownership::slices::words;
+///
+/// let mut s = String::from("one two");
+/// let mut iter = words(&s);
+/// s.push_str(" three"); // error: cannot borrow `s` as mutable because it is also borrowed as immutable
+/// println!("{:?}", iter.next());
+/// ```
+pub fn words(s: &str) -> Words<'_> {
+    Words {
+        source: s,
+        offset: 0,
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = &self.source[self.offset..];
+        let trimmed = rest.trim_start();
+        if trimmed.is_empty() {
+            self.offset = self.source.len();
+            return None;
+        }
+        let start = self.offset + (rest.len() - trimmed.len());
+        let len = trimmed
+            .char_indices()
+            .find(|&(_, c)| c.is_whitespace())
+            .map_or(trimmed.len(), |(i, _)| i);
+        self.offset = start + len;
+        Some(&self.source[start..self.offset])
+    }
+}
+
+// Recall from the borrowing rules that if we have an immutable refer- ence to something, we cannot also take a mutable reference.
+
+// String Literals are Slices
+// The type of a string literal is &str: it’s a slice pointing to that specific point of the binary, and this is why string literals are fast and efficient, they are immutable!
+
+// Other Slices
+pub fn middle(a: &[i32]) -> &[i32] {
+    &a[1..3]
+}
+
+pub struct SlicesExample;
+
+impl Example for SlicesExample {
+    fn name(&self) -> &str {
+        "slices"
+    }
+
+    fn description(&self) -> &str {
+        "references to a contiguous part of a collection"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("slices.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        // String Slices
+        let s = String::from("hello world");
+        let hello = &s[0..5]; // &s[..5]
+        let world = &s[6..11]; // &s[6..]
+        let whole = &s[..]; // &s[..]
+        writeln!(out, "{} {} {}", hello, world, whole)?;
+
+        // String Slices as Parameters
+        let my_string_slices = String::from("hello world");
+        let word = first_word(&my_string_slices[..]);
+        writeln!(out, "The first word is: {}", word)?;
+
+        let my_string_slices_literal = "hello world";
+        #[allow(clippy::redundant_slicing)] // same call as above, on a literal
+        let word = first_word(&my_string_slices_literal[..]);
+        writeln!(out, "The first word is: {}", word)?;
+
+        // Because string literals are string slices already, this works without the slice syntax!
+        let word = first_word(my_string_slices_literal);
+        writeln!(out, "The first word is: {}", word)?;
+
+        let sentence = String::from("the  quick brown fox ");
+        let all: Vec<&str> = words(&sentence).collect();
+        writeln!(out, "The words are: {:?}", all)?;
+
+        let a = [1, 2, 3, 4, 5];
+        writeln!(out, "{:?}", middle(&a)) // &[2, 3]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_word_stops_at_the_first_space() {
+        assert_eq!(first_word("hello world"), "hello");
+    }
+
+    #[test]
+    fn first_word_without_space_is_the_whole_string() {
+        assert_eq!(first_word("hello"), "hello");
+    }
+
+    #[test]
+    fn first_word_of_empty_string_is_empty() {
+        assert_eq!(first_word(""), "");
+        assert_eq!(first_word("   "), "");
+    }
+
+    #[test]
+    fn first_word_skips_leading_whitespace() {
+        assert_eq!(first_word("  \thello world"), "hello");
+    }
+
+    #[test]
+    fn first_word_handles_multi_byte_characters() {
+        assert_eq!(first_word("héllo wörld"), "héllo");
+        assert_eq!(first_word("日本語\u{3000}テキスト"), "日本語");
+    }
+
+    #[test]
+    fn first_word_borrows_from_the_input() {
+        let s = String::from("hello world");
+        assert_eq!(first_word(&s).as_ptr(), s.as_ptr());
+    }
+
+    fn assert_words_match(s: &str) {
+        let ours: Vec<&str> = words(s).collect();
+        let std: Vec<&str> = s.split_whitespace().collect();
+        assert_eq!(ours, std, "input {:?}", s);
+    }
+
+    #[test]
+    fn words_matches_split_whitespace() {
+        for s in [
+            "",
+            " ",
+            "one",
+            "one two",
+            "  one   two  ",
+            "one\ttwo\nthree ",
+            "héllo  wörld",
+            "a b c d",
+        ] {
+            assert_words_match(s);
+        }
+    }
+
+    #[test]
+    fn words_is_fused_after_the_end() {
+        let mut iter = words("one  ");
+        assert_eq!(iter.next(), Some("one"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn words_borrow_from_the_input() {
+        let s = String::from("one two");
+        let second = words(&s).nth(1).unwrap();
+        assert_eq!(second.as_ptr(), s[4..].as_ptr());
+    }
+
+    #[test]
+    fn middle_slices_an_array() {
+        assert_eq!(middle(&[1, 2, 3, 4, 5]), &[2, 3]);
+    }
+}
@@ -0,0 +1,149 @@
+// Statics: OnceLock and LazyLock -------------------------------
+// A static is owned by the program itself, so a reference to it is `&'static` like a string
+// literal (see lifetimes::static_demo). OnceLock fills one in at run time, the first caller wins;
+// LazyLock does the same with the initializer fixed where the static is declared
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, OnceLock};
+
+use crate::example::{Example, Rule, RunConfig};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub name: String,
+    pub version: String,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// How many times [`load_config`] ran, which OnceLock keeps at one at most.
+static CONFIG_INITS: AtomicUsize = AtomicUsize::new(0);
+
+fn load_config() -> Config {
+    CONFIG_INITS.fetch_add(1, Ordering::SeqCst);
+    Config {
+        name: String::from(env!("CARGO_PKG_NAME")),
+        version: String::from(env!("CARGO_PKG_VERSION")),
+    }
+}
+
+/// The process-wide Config, built on first use. Every thread gets the same one.
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(load_config)
+}
+
+/// Installs `config` if nothing has been installed or built yet, otherwise hands it back.
+pub fn set_config(config: Config) -> Result<(), Config> {
+    CONFIG.set(config)
+}
+
+static MESSAGES: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    [
+        ("moved", "the value has a new owner"),
+        ("borrowed", "the owner lent it out"),
+        ("dropped", "the owner went out of scope"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+});
+
+/// A canned message, borrowed from the static map for as long as the program runs.
+pub fn message(key: &str) -> Option<&'static str> {
+    MESSAGES.get(key).map(String::as_str)
+}
+
+pub struct StaticsExample;
+
+impl Example for StaticsExample {
+    fn name(&self) -> &str {
+        "statics"
+    }
+
+    fn description(&self) -> &str {
+        "OnceLock and LazyLock own data for the whole program"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("statics.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let first: &'static Config = config();
+        writeln!(out, "config() = {:?}", first)?;
+        writeln!(
+            out,
+            "a second call returns the same value: {}",
+            std::ptr::eq(first, config())
+        )?;
+        let rejected = set_config(Config {
+            name: String::from("other"),
+            version: String::from("0.0.0"),
+        });
+        writeln!(
+            out,
+            "set_config after first use -> rejected, got back {:?}",
+            rejected.map_err(|c| c.name)
+        )?;
+        for key in ["moved", "borrowed", "dropped"] {
+            writeln!(
+                out,
+                "message({:?}) = {:?}",
+                key,
+                message(key).unwrap_or_default()
+            )?;
+        }
+        writeln!(
+            out,
+            "the program owns both statics, so nothing ever drops them and their references are 'static"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn config_is_initialized_once_across_threads() {
+        let threads: Vec<_> = (0..8)
+            .map(|_| thread::spawn(|| config() as *const Config as usize))
+            .collect();
+        let addresses: Vec<usize> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert!(addresses.iter().all(|&a| a == addresses[0]));
+        assert_eq!(CONFIG_INITS.load(Ordering::SeqCst), 1);
+        assert_eq!(config().name, "ownership");
+    }
+
+    #[test]
+    fn second_set_is_rejected() {
+        config();
+        let other = Config {
+            name: String::from("other"),
+            version: String::new(),
+        };
+        assert_eq!(set_config(other.clone()), Err(other));
+        assert_eq!(config().name, "ownership");
+    }
+
+    #[test]
+    fn a_fresh_once_lock_takes_one_set() {
+        let lock = OnceLock::new();
+        assert_eq!(lock.set(1), Ok(()));
+        assert_eq!(lock.set(2), Err(2));
+        assert_eq!(lock.get(), Some(&1));
+    }
+
+    #[test]
+    fn messages_are_static() {
+        let moved: &'static str = message("moved").unwrap();
+        assert_eq!(moved, "the value has a new owner");
+        assert_eq!(message("missing"), None);
+    }
+}
@@ -0,0 +1,183 @@
+// Building Strings ---------------------------------------------
+// There are three common ways to glue text together and each treats ownership differently:
+// `+` takes the left String by value, format! only borrows and push_str grows a String in place
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::ownership::strings;
+
+/// Concatenates with `+`, which moves `s1` in and reuses its buffer for the result.
+///
+/// `s1` has been moved into the result, so it can't be used afterwards:
+///
+/// ```compile_fail
+/// let s1 = String::from("Hello, ");
+/// let s2 = String::from("world!");
+/// let s3 = s1 + &s2;
+/// println!("{} {}", s1, s3); // error: borrow of moved value: `s1`
+/// ```
+pub fn with_plus(s1: String, s2: &str) -> String {
+    s1 + s2
+}
+
+/// Concatenates with `format!`, which borrows both parts and allocates a new String.
+pub fn with_format(s1: &str, s2: &str) -> String {
+    format!("{}{}", s1, s2)
+}
+
+/// Concatenates with `push_str`, growing `s1` in place.
+pub fn with_push_str(s1: &mut String, s2: &str) {
+    s1.push_str(s2);
+}
+
+/// Exact number of bytes `join_with` needs for `parts` joined by `sep`.
+pub fn joined_len(parts: &[String], sep: &str) -> usize {
+    let text: usize = parts.iter().map(String::len).sum();
+    text + sep.len() * parts.len().saturating_sub(1)
+}
+
+/// Joins `parts` with `sep`, reserving the whole buffer up front so it is allocated only once.
+pub fn join_with(parts: &[String], sep: &str) -> String {
+    let mut joined = String::with_capacity(joined_len(parts, sep));
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(sep);
+        }
+        joined.push_str(part);
+    }
+    joined
+}
+
+pub struct StringBuildingExample;
+
+impl Example for StringBuildingExample {
+    fn name(&self) -> &str {
+        "string-building"
+    }
+
+    fn description(&self) -> &str {
+        "+ vs format! vs push_str"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("string_building.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, config: &RunConfig) -> io::Result<()> {
+        let s1 = String::from("Hello, ");
+        let s2 = String::from("World!");
+        config.trace(out, "s1", &s1)?;
+        config.trace(out, "s2", &s2)?;
+        writeln!(
+            out,
+            "format!:  {} (s1 and s2 only borrowed)",
+            with_format(&s1, &s2)
+        )?;
+        let plus = with_plus(s1, &s2);
+        writeln!(out, "+:        {} (s1 moved in)", plus)?;
+        config.trace(out, "s1 + &s2", &plus)?;
+        writeln!(out, "push_str: {}", strings::push_str_demo())?;
+
+        let parts: Vec<String> = ["alpha", "beta", "gamma", "delta"].map(String::from).into();
+        let mut grown = String::new();
+        let mut capacities = vec![grown.capacity()];
+        for part in &parts {
+            with_push_str(&mut grown, part);
+            capacities.push(grown.capacity());
+            config.trace(out, &format!("push_str {:?}", part), &grown)?;
+        }
+        writeln!(out, "growing from empty, capacities {:?}", capacities)?;
+
+        let joined = join_with(&parts, ", ");
+        config.trace(out, "join_with", &joined)?;
+        writeln!(
+            out,
+            "join_with: {:?}, len {}, capacity {} (reserved once)",
+            joined,
+            joined.len(),
+            joined.capacity()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_three_approaches_agree() {
+        let plus = with_plus(String::from("foo"), "bar");
+        let format = with_format("foo", "bar");
+        let mut push = String::from("foo");
+        with_push_str(&mut push, "bar");
+        assert_eq!(plus, "foobar");
+        assert_eq!(format, plus);
+        assert_eq!(push, plus);
+    }
+
+    #[test]
+    fn plus_reuses_the_left_buffer() {
+        let mut s1 = String::with_capacity(16);
+        s1.push_str("foo");
+        let ptr = s1.as_ptr();
+        let s3 = with_plus(s1, "bar");
+        assert_eq!(s3.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn join_with_matches_the_std_join() {
+        let parts: Vec<String> = ["a", "bb", "ccc"].map(String::from).into();
+        assert_eq!(join_with(&parts, ", "), parts.join(", "));
+        assert_eq!(join_with(&parts[..1], ", "), "a");
+        assert_eq!(join_with(&[], ", "), "");
+    }
+
+    #[test]
+    fn join_with_allocates_exactly_once() {
+        let parts: Vec<String> = ["alpha", "beta", "gamma", "delta"].map(String::from).into();
+        let joined = join_with(&parts, " - ");
+        // a regrowth would have pushed the capacity past what with_capacity reserved
+        assert_eq!(joined.len(), joined_len(&parts, " - "));
+        assert_eq!(joined.capacity(), joined_len(&parts, " - "));
+    }
+
+    fn run(verbose: bool) -> String {
+        let config = RunConfig {
+            verbose,
+            ..RunConfig::default()
+        };
+        let mut out = Vec::new();
+        StringBuildingExample.run(&mut out, &config).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn quiet_output_is_unchanged() {
+        assert_eq!(
+            run(false),
+            "format!:  Hello, World! (s1 and s2 only borrowed)\n\
+             +:        Hello, World! (s1 moved in)\n\
+             push_str: Hello, World!\n\
+             growing from empty, capacities [0, 8, 16, 16, 32]\n\
+             join_with: \"alpha, beta, gamma, delta\", len 25, capacity 25 (reserved once)\n"
+        );
+    }
+
+    #[test]
+    fn verbose_output_traces_every_string() {
+        let quiet = run(false);
+        let verbose = run(true);
+        assert!(!quiet.contains("ptr 0x"));
+        let traces: Vec<&str> = verbose.lines().filter(|l| l.starts_with("  [")).collect();
+        assert_eq!(traces.len(), 8); // s1, s2, the + result, four push_str calls and the join
+        assert!(traces.iter().all(|l| l.contains("ptr 0x")));
+        assert!(traces[3].starts_with("  [push_str \"alpha\"] ptr "));
+        assert!(traces[7].ends_with("len 25, capacity 25"));
+        let untraced: Vec<&str> = verbose.lines().filter(|l| !l.starts_with("  [")).collect();
+        assert_eq!(untraced, quiet.lines().collect::<Vec<_>>());
+    }
+}
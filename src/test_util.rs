@@ -0,0 +1,6 @@
+// Fixtures shared by the unit tests
+
+/// Owned copies of `words`, for tests that need a `Vec<String>` to move or mutate.
+pub fn strings(words: &[&str]) -> Vec<String> {
+    words.iter().map(|w| w.to_string()).collect()
+}
@@ -0,0 +1,99 @@
+// Channels: Moving Values Between Threads -------------------------
+// `tx.send(value)` takes the value by value, exactly like `takes_ownership(String)`: once sent,
+// the String belongs to whichever thread receives it
+use std::io::{self, Write};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Sends every input String to a worker thread, which answers with their lengths in bytes.
+///
+/// A sent value has moved, the sending thread can't use it any more:
+///
+/// ```compile_fail
+/// use std::sync::mpsc;
+///
+/// let (tx, rx) = mpsc::channel();
+/// let s = String::from("hello");
+/// tx.send(s).unwrap();
+/// println!("{}", s); // error: borrow of moved value: `s`
+/// # drop(rx);
+/// ```
+pub fn pipeline(inputs: Vec<String>) -> Vec<usize> {
+    let (to_worker, from_main) = mpsc::channel::<String>();
+    let (to_main, from_worker) = mpsc::channel::<usize>();
+
+    let worker = thread::spawn(move || {
+        // the loop ends once every sender of `from_main` is dropped
+        for s in from_main {
+            to_main.send(s.len()).unwrap();
+        } // each received s is dropped here, on the worker thread
+    });
+
+    for s in inputs {
+        to_worker.send(s).unwrap(); // s moves into the channel
+    }
+    drop(to_worker); // hang up, otherwise the worker waits forever
+
+    let lengths = from_worker.iter().collect();
+    worker.join().unwrap();
+    lengths
+}
+
+pub struct ChannelsExample;
+
+impl Example for ChannelsExample {
+    fn name(&self) -> &str {
+        "channels"
+    }
+
+    fn description(&self) -> &str {
+        "sending a value moves it to another thread"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("channels.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let inputs = vec![
+            String::from("hello"),
+            String::from("world"),
+            String::from("héllo"),
+        ];
+        writeln!(out, "sending {:?} to the worker", inputs)?;
+        let lengths = pipeline(inputs); // inputs is moved, its Strings now live on the worker
+        writeln!(out, "lengths received back: {:?}", lengths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths_come_back_in_order() {
+        let inputs = vec![String::from("a"), String::from("abc"), String::new()];
+        assert_eq!(pipeline(inputs), [1, 3, 0]);
+    }
+
+    #[test]
+    fn empty_input_gives_empty_output() {
+        assert!(pipeline(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn lengths_are_in_bytes() {
+        let inputs = vec![
+            String::from("héllo"),
+            String::from("日本"),
+            String::from("🦀"),
+        ];
+        assert_eq!(pipeline(inputs), [6, 6, 4]);
+    }
+}
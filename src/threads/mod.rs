@@ -0,0 +1,6 @@
+// Threads: ownership rules across thread boundaries
+pub mod channels;
+pub mod move_closures;
+pub mod rwlock_demo;
+pub mod scoped;
+pub mod shared_state;
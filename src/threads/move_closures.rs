@@ -0,0 +1,94 @@
+// Move Closures and thread::spawn ---------------------------------
+// A spawned thread may outlive the function that spawned it, so its closure can't borrow locals:
+// `move` makes the closure take ownership of everything it captures
+use std::io::{self, Write};
+use std::thread::{self, JoinHandle};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Moves `v` into a new thread that sums it.
+///
+/// Without `move` the closure would only borrow `v`, which may not live long enough:
+///
+/// ```compile_fail
+/// use std::thread;
+///
+/// let v = vec![1, 2, 3];
+/// let handle = thread::spawn(|| v.iter().sum::<i32>()); // error: closure may outlive the current function, but it borrows `v`
+/// handle.join().unwrap();
+/// ```
+pub fn spawn_sum(v: Vec<i32>) -> JoinHandle<i32> {
+    thread::spawn(move || v.iter().sum())
+}
+
+/// Captures an `i32` by move; it's Copy, so the caller's `factor` is still usable afterwards.
+pub fn copy_capture(factor: i32) -> (i32, i32) {
+    let handle = thread::spawn(move || factor * 10); // the closure gets its own copy
+    let result = handle.join().unwrap();
+    (result, factor) // factor was copied, not moved
+}
+
+pub struct MoveClosuresExample;
+
+impl Example for MoveClosuresExample {
+    fn name(&self) -> &str {
+        "move-closures"
+    }
+
+    fn description(&self) -> &str {
+        "thread closures must own what they capture"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("move_closures.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let v = vec![1, 2, 3, 4];
+        writeln!(out, "moving {:?} into a thread", v)?;
+        let sum = spawn_sum(v).join().unwrap(); // v belongs to the thread now
+        writeln!(out, "sum from the joined handle: {}", sum)?;
+
+        let (result, factor) = copy_capture(7);
+        writeln!(
+            out,
+            "copied factor {} into a thread, got {} back, factor still usable",
+            factor, result
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joined_handle_returns_the_sum() {
+        assert_eq!(spawn_sum(vec![1, 2, 3]).join().unwrap(), 6);
+        assert_eq!(spawn_sum(Vec::new()).join().unwrap(), 0);
+    }
+
+    #[test]
+    fn copy_types_stay_usable_after_a_move_closure() {
+        assert_eq!(copy_capture(4), (40, 4));
+    }
+
+    #[test]
+    fn every_thread_owns_its_own_clone() {
+        let base = vec![1, 2, 3];
+        let handles: Vec<_> = (1..=4)
+            .map(|k| {
+                let mut own: Vec<i32> = base.clone();
+                own.push(k);
+                spawn_sum(own)
+            })
+            .collect();
+        let sums: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(sums, [7, 8, 9, 10]);
+        assert_eq!(base, [1, 2, 3]); // the original was never moved
+    }
+}
@@ -0,0 +1,147 @@
+// Many Readers, One Writer: Arc<RwLock<T>> ---------------------
+// The borrow rule "any number of `&T` or exactly one `&mut T`" checked at run time, across threads:
+// read() hands out shared guards to as many readers as ask, write() waits until it is the only one
+use std::io::{self, Write};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// What the readers saw while the writer filled the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RwStats {
+    /// Entries in the log once every thread was joined, always the number of writes.
+    pub log_len: usize,
+    /// Bytes in the log at the end.
+    pub log_bytes: usize,
+    /// How many read locks each reader took; depends on scheduling.
+    pub reads: Vec<usize>,
+    /// Whether every reader saw the log only grow, with the bytes matching the entries each time.
+    pub consistent: bool,
+}
+
+fn entry(i: usize) -> String {
+    format!("entry {}", i)
+}
+
+/// The bytes in the first `len` entries the writer appends.
+fn bytes_of(len: usize) -> usize {
+    (0..len).map(|i| entry(i).len()).sum()
+}
+
+/// Spawns `readers` threads reading the shared log until it is complete, and one writer
+/// appending `writes` entries to it.
+pub fn run(readers: usize, writes: usize) -> RwStats {
+    let log = Arc::new(RwLock::new(Vec::<String>::new()));
+    let reader_threads: Vec<_> = (0..readers)
+        .map(|_| {
+            let log = Arc::clone(&log);
+            thread::spawn(move || {
+                let (mut reads, mut last, mut consistent) = (0, 0, true);
+                while last < writes {
+                    let (len, bytes) = {
+                        let entries = log.read().unwrap(); // shared, other readers get in too
+                        (
+                            entries.len(),
+                            entries.iter().map(String::len).sum::<usize>(),
+                        )
+                    }; // the read guard is dropped here, so the writer can get in
+                    consistent &= len >= last && bytes == bytes_of(len);
+                    last = len;
+                    reads += 1;
+                    thread::yield_now();
+                }
+                (reads, consistent)
+            })
+        })
+        .collect();
+    let writer = {
+        let log = Arc::clone(&log);
+        thread::spawn(move || {
+            for i in 0..writes {
+                log.write().unwrap().push(entry(i)); // exclusive, waits for the readers to let go
+            }
+        })
+    };
+    writer.join().unwrap();
+    let (reads, consistent): (Vec<usize>, Vec<bool>) = reader_threads
+        .into_iter()
+        .map(|t| t.join().unwrap())
+        .unzip();
+    let entries = log.read().unwrap();
+    RwStats {
+        log_len: entries.len(),
+        log_bytes: entries.iter().map(String::len).sum(),
+        reads,
+        consistent: consistent.into_iter().all(|c| c),
+    }
+}
+
+pub struct RwLockExample;
+
+impl Example for RwLockExample {
+    fn name(&self) -> &str {
+        "rwlock"
+    }
+
+    fn description(&self) -> &str {
+        "many readers or one writer, across threads"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("rwlock_demo.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let stats = run(4, 50);
+        writeln!(
+            out,
+            "{} readers and one writer: the log ended with {} entries, {} bytes",
+            stats.reads.len(),
+            stats.log_len,
+            stats.log_bytes
+        )?;
+        writeln!(
+            out,
+            "every reader saw the log only grow, never half written: {}",
+            stats.consistent
+        )?;
+        writeln!(
+            out,
+            "read() is the runtime `&T`, write() the runtime `&mut T`: many readers XOR one writer"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn final_log_has_every_write() {
+        let stats = run(3, 200);
+        assert_eq!(stats.log_len, 200);
+        assert_eq!(stats.log_bytes, bytes_of(200));
+        assert_eq!(stats.reads.len(), 3);
+    }
+
+    #[test]
+    fn readers_never_see_a_torn_or_shrinking_log() {
+        let stats = run(8, 500);
+        assert!(stats.consistent);
+        assert!(stats.reads.iter().all(|&r| r >= 1));
+    }
+
+    #[test]
+    fn no_readers_or_no_writes() {
+        assert_eq!(run(0, 10).log_len, 10);
+        let stats = run(2, 0);
+        assert_eq!(stats.log_len, 0);
+        assert_eq!(stats.reads, [0, 0]);
+        assert!(stats.consistent);
+    }
+}
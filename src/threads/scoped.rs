@@ -0,0 +1,135 @@
+// Scoped Threads -----------------------------------------------
+// `thread::spawn` needs `'static` data because the thread may outlive the caller. `thread::scope`
+// joins every thread before it returns, so its threads can borrow locals: shared borrows in
+// parallel, or disjoint `&mut` chunks of one buffer
+use std::io::{self, Write};
+use std::thread;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// The length of a chunk when `len` items are split over `threads` threads, 0 counting as 1.
+fn chunk_len(len: usize, threads: usize) -> usize {
+    len.div_ceil(threads.max(1)).max(1)
+}
+
+/// Counts the chars of every line, each scoped thread borrowing one chunk of `lines`.
+///
+/// A plain spawned thread can't borrow the local, it might still be running after it is dropped:
+///
+/// ```compile_fail
+/// use std::thread;
+///
+/// let lines = vec![String::from("hello")];
+/// let handle = thread::spawn(|| lines.len()); // error: closure may outlive the current function, but it borrows `lines`
+/// handle.join().unwrap();
+/// ```
+pub fn parallel_char_count(lines: &[String], threads: usize) -> usize {
+    thread::scope(|s| {
+        let handles: Vec<_> = lines
+            .chunks(chunk_len(lines.len(), threads))
+            .map(|chunk| s.spawn(move || chunk.iter().map(|l| l.chars().count()).sum::<usize>()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    }) // every thread has been joined here, the borrow of lines ends with the scope
+}
+
+/// Sets every byte of `buf` to `value`, each scoped thread owning the `&mut` to one chunk.
+pub fn parallel_fill(buf: &mut [u8], value: u8, threads: usize) {
+    let len = chunk_len(buf.len(), threads);
+    thread::scope(|s| {
+        for chunk in buf.chunks_mut(len) {
+            s.spawn(move || chunk.fill(value));
+        }
+    });
+}
+
+pub struct ScopedThreadsExample;
+
+impl Example for ScopedThreadsExample {
+    fn name(&self) -> &str {
+        "scoped-threads"
+    }
+
+    fn description(&self) -> &str {
+        "thread::scope lets threads borrow local data"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::DropAtScopeEnd
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("scoped.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let lines: Vec<String> = ["one owner", "many readers", "or one writer", "héllo"]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        writeln!(
+            out,
+            "3 threads borrowing {} lines counted {} chars, lines is still ours: {:?}",
+            lines.len(),
+            parallel_char_count(&lines, 3),
+            lines[0]
+        )?;
+        let mut buf = [0u8; 10];
+        parallel_fill(&mut buf, 7, 4);
+        writeln!(
+            out,
+            "4 threads each filled their own chunks_mut chunk: {:?}",
+            buf
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(n: usize) -> Vec<String> {
+        (0..n).map(|i| "ä".repeat(i % 7)).collect()
+    }
+
+    fn serial_char_count(lines: &[String]) -> usize {
+        lines.iter().map(|l| l.chars().count()).sum()
+    }
+
+    #[test]
+    fn char_count_matches_the_serial_count() {
+        let lines = lines(100);
+        for threads in [1, 3, 8, 100] {
+            assert_eq!(
+                parallel_char_count(&lines, threads),
+                serial_char_count(&lines)
+            );
+        }
+    }
+
+    #[test]
+    fn char_count_with_more_threads_than_lines_or_none() {
+        let lines = lines(3);
+        assert_eq!(parallel_char_count(&lines, 16), serial_char_count(&lines));
+        assert_eq!(parallel_char_count(&[], 4), 0);
+        assert_eq!(parallel_char_count(&lines, 0), serial_char_count(&lines));
+    }
+
+    #[test]
+    fn fill_matches_the_serial_fill() {
+        for (len, threads) in [(10, 3), (10, 10), (3, 16), (1, 0), (0, 4)] {
+            let mut parallel = vec![1u8; len];
+            parallel_fill(&mut parallel, 9, threads);
+            let mut serial = vec![1u8; len];
+            serial.fill(9);
+            assert_eq!(parallel, serial, "len {} threads {}", len, threads);
+        }
+    }
+
+    #[test]
+    fn fill_a_subslice_only() {
+        let mut buf = [0u8; 8];
+        parallel_fill(&mut buf[2..6], 5, 3);
+        assert_eq!(buf, [0, 0, 5, 5, 5, 5, 0, 0]);
+    }
+}
@@ -0,0 +1,91 @@
+// Shared State: Arc<Mutex<T>> -------------------------------------
+// Arc is the thread-safe Rc (atomic reference count), Mutex hands out one `&mut` at a time across
+// threads. Together they give several threads shared ownership of one mutable value
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Spawns `threads` threads that each add 1 to a shared counter `increments` times, and returns
+/// the final count, always `threads * increments`.
+///
+/// `Rc` can't be used instead of `Arc`, its count isn't updated atomically so it isn't `Send`:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use std::sync::Mutex;
+/// use std::thread;
+///
+/// let counter = Rc::new(Mutex::new(0u64));
+/// let handle = {
+///     let counter = Rc::clone(&counter);
+///     thread::spawn(move || *counter.lock().unwrap() += 1) // error: `Rc<Mutex<u64>>` cannot be sent between threads safely
+/// };
+/// handle.join().unwrap();
+/// ```
+pub fn count_with_threads(threads: usize, increments: u64) -> u64 {
+    let counter = Arc::new(Mutex::new(0u64));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter); // each thread owns its own handle
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    *counter.lock().unwrap() += 1; // the guard unlocks when dropped
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let count = *counter.lock().unwrap();
+    count
+}
+
+pub struct SharedStateExample;
+
+impl Example for SharedStateExample {
+    fn name(&self) -> &str {
+        "shared-state"
+    }
+
+    fn description(&self) -> &str {
+        "shared ownership across threads with Arc<Mutex<T>>"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("shared_state.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let (threads, increments) = (8, 1000);
+        writeln!(
+            out,
+            "{} threads x {} increments = {}",
+            threads,
+            increments,
+            count_with_threads(threads, increments)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn final_count_is_exact() {
+        assert_eq!(count_with_threads(8, 1000), 8000);
+    }
+
+    #[test]
+    fn no_threads_count_nothing() {
+        assert_eq!(count_with_threads(0, 1000), 0);
+        assert_eq!(count_with_threads(4, 0), 0);
+    }
+}
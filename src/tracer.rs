@@ -0,0 +1,66 @@
+// Tracer: a value that records when it is created and when it is dropped
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Shared, injectable sink the tracers write their events to.
+pub type DropLog = Rc<RefCell<Vec<String>>>;
+
+pub fn new_log() -> DropLog {
+    Rc::new(RefCell::new(Vec::new()))
+}
+
+/// Records a plain event, such as the end of a scope, between the tracers' own.
+pub fn mark(log: &DropLog, what: &str) {
+    log.borrow_mut().push(what.to_string());
+}
+
+/// Runs `demo` against a fresh log and returns everything it recorded.
+#[cfg(test)]
+pub fn events(demo: fn(&DropLog)) -> Vec<String> {
+    let log = new_log();
+    demo(&log);
+    let events = log.borrow().clone();
+    events
+}
+
+/// Logs `new: <label>` when created and `drop: <label>` when dropped.
+pub struct Tracer {
+    label: String,
+    log: DropLog,
+}
+
+impl Tracer {
+    pub fn new(label: &str, log: &DropLog) -> Tracer {
+        log.borrow_mut().push(format!("new: {}", label));
+        Tracer {
+            label: label.to_string(),
+            log: Rc::clone(log),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(format!("drop: {}", self.label));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logs_creation_and_drop() {
+        let log = new_log();
+        {
+            let t = Tracer::new("a", &log);
+            assert_eq!(t.label(), "a");
+            assert_eq!(*log.borrow(), ["new: a"]);
+        }
+        assert_eq!(*log.borrow(), ["new: a", "drop: a"]);
+    }
+}
@@ -0,0 +1,179 @@
+// Trees with Parent Pointers -----------------------------------
+// A parent owns its children through Rc, a child only points back at its parent through Weak.
+// If the back edge were an Rc too, parent and child would own each other and neither would be freed
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::{Rc, Weak};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::tracer::{new_log, DropLog, Tracer};
+
+pub struct Node {
+    pub value: i32,
+    pub children: RefCell<Vec<Rc<Node>>>,
+    pub parent: RefCell<Weak<Node>>,
+    _tracer: Tracer,
+}
+
+impl Node {
+    pub fn new(value: i32, log: &DropLog) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            children: RefCell::new(Vec::new()),
+            parent: RefCell::new(Weak::new()),
+            _tracer: Tracer::new(&format!("node {}", value), log),
+        })
+    }
+
+    /// Makes `parent` an owner of `child` and gives `child` a non-owning edge back.
+    pub fn add_child(parent: &Rc<Node>, child: Rc<Node>) {
+        *child.parent.borrow_mut() = Rc::downgrade(parent);
+        parent.children.borrow_mut().push(child);
+    }
+
+    /// Number of ancestors still alive above this node, 0 for a root.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut parent = self.parent.borrow().upgrade();
+        while let Some(node) = parent {
+            depth += 1;
+            parent = node.parent.borrow().upgrade();
+        }
+        depth
+    }
+
+    /// Sum of this node's value and every value below it.
+    pub fn sum(&self) -> i32 {
+        self.value + self.children.borrow().iter().map(|c| c.sum()).sum::<i32>()
+    }
+}
+
+fn counts(name: &str, node: &Rc<Node>) -> String {
+    format!(
+        "{}: strong = {}, weak = {}",
+        name,
+        Rc::strong_count(node),
+        Rc::weak_count(node)
+    )
+}
+
+/// Attaches a leaf to a branch, then drops the branch and shows the leaf survives on its own.
+pub fn parent_and_leaf(log: &DropLog, out: &mut dyn Write) -> io::Result<()> {
+    let leaf = Node::new(3, log);
+    writeln!(out, "{}", counts("leaf", &leaf))?;
+    {
+        let branch = Node::new(5, log);
+        Node::add_child(&branch, Rc::clone(&leaf));
+        writeln!(out, "{}", counts("leaf", &leaf))?; // the branch owns it too now
+        writeln!(out, "{}", counts("branch", &branch))?; // the leaf's Weak doesn't own it
+        writeln!(
+            out,
+            "leaf depth = {}, branch sum = {}",
+            leaf.depth(),
+            branch.sum()
+        )?;
+    } // branch's strong count drops to 0, so it is freed despite the leaf's Weak
+    let parent = leaf.parent.borrow().upgrade();
+    writeln!(
+        out,
+        "after the branch is dropped: parent = {:?}",
+        parent.map(|p| p.value)
+    )?;
+    writeln!(out, "{}", counts("leaf", &leaf))
+}
+
+pub struct TreeExample;
+
+impl Example for TreeExample {
+    fn name(&self) -> &str {
+        "tree"
+    }
+
+    fn description(&self) -> &str {
+        "Rc children and Weak parents"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("tree.rs")
+    }
+
+    fn run(&self, mut out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let log = new_log();
+        parent_and_leaf(&log, &mut out)?;
+        let events = log.borrow();
+        writeln!(out, "{}", events.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// root(1) -> [a(2) -> [c(4), d(5)], b(3)]
+    fn three_levels(log: &DropLog) -> (Rc<Node>, Rc<Node>) {
+        let root = Node::new(1, log);
+        let a = Node::new(2, log);
+        let d = Node::new(5, log);
+        Node::add_child(&a, Node::new(4, log));
+        Node::add_child(&a, Rc::clone(&d));
+        Node::add_child(&root, a);
+        Node::add_child(&root, Node::new(3, log));
+        (root, d)
+    }
+
+    #[test]
+    fn depth_counts_ancestors() {
+        let log = new_log();
+        let (root, leaf) = three_levels(&log);
+        assert_eq!(root.depth(), 0);
+        assert_eq!(root.children.borrow()[0].depth(), 1);
+        assert_eq!(leaf.depth(), 2);
+    }
+
+    #[test]
+    fn sum_walks_every_level() {
+        let log = new_log();
+        let (root, _) = three_levels(&log);
+        assert_eq!(root.sum(), 1 + 2 + 3 + 4 + 5);
+        assert_eq!(root.children.borrow()[0].sum(), 2 + 4 + 5);
+    }
+
+    #[test]
+    fn weak_parent_is_gone_after_the_parent_drops() {
+        let log = new_log();
+        let leaf = Node::new(1, &log);
+        let parent = Node::new(2, &log);
+        Node::add_child(&parent, Rc::clone(&leaf));
+        assert_eq!(leaf.parent.borrow().upgrade().map(|p| p.value), Some(2));
+        drop(parent);
+        assert!(leaf.parent.borrow().upgrade().is_none());
+        assert_eq!(leaf.depth(), 0);
+    }
+
+    #[test]
+    fn every_node_is_freed() {
+        let log = new_log();
+        let (root, leaf) = three_levels(&log);
+        drop(root);
+        assert_eq!(
+            log.borrow()
+                .iter()
+                .filter(|e| e.starts_with("drop"))
+                .count(),
+            4
+        );
+        drop(leaf);
+        let log = log.borrow();
+        for value in 1..=5 {
+            let dropped = log
+                .iter()
+                .filter(|e| **e == format!("drop: node {}", value))
+                .count();
+            assert_eq!(dropped, 1);
+        }
+    }
+}
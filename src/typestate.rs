@@ -0,0 +1,169 @@
+// Typestate: States as Types -----------------------------------
+// Each state of the door is its own type and every transition takes the old state by value, so
+// after `door.open()` the closed door no longer exists and nothing can be done to it by mistake.
+// Operations that make no sense in a state, like opening a locked door, simply don't exist there
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// A key cut for a code. Two keys cut for the same code open the same lock.
+#[derive(Debug, PartialEq)]
+pub struct Key {
+    code: u32,
+}
+
+impl Key {
+    pub fn cut(code: u32) -> Key {
+        Key { code }
+    }
+}
+
+/// An open door, reached only by opening a closed one.
+///
+/// Its field is private, so other modules can't skip the transitions and write one themselves:
+///
+/// ```compile_fail
+/// use ownership::typestate::Open;
+///
+/// let door = Open(()); // error: cannot initialize a tuple struct which contains private fields
+/// ```
+pub struct Open(());
+
+/// A closed, unlocked door, from [`new_door`], closing an open one or unlocking a locked one.
+///
+/// Its field is private too, so nobody outside the module can conjure one up without the key:
+///
+/// ```compile_fail
+/// use ownership::typestate::Closed;
+///
+/// let door = Closed(()); // error: cannot initialize a tuple struct which contains private fields
+/// ```
+pub struct Closed(());
+
+/// A new door, which starts out closed. The only way in to the states.
+pub fn new_door() -> Closed {
+    Closed(())
+}
+
+impl fmt::Debug for Open {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Open")
+    }
+}
+
+impl fmt::Debug for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Closed")
+    }
+}
+
+/// A locked door, it keeps the key it was locked with to check the one used to unlock it.
+///
+/// There is no `open` on a locked door, it has to be unlocked first:
+///
+/// ```compile_fail
+/// use ownership::typestate::{new_door, Key};
+///
+/// let locked = new_door().lock(Key::cut(1));
+/// let open = locked.open(); // error: no method named `open` found for struct `Locked`
+/// ```
+#[derive(Debug)]
+pub struct Locked {
+    key: Key,
+}
+
+impl Closed {
+    /// The closed door is consumed, only the open one is left:
+    ///
+    /// ```compile_fail
+    /// use ownership::typestate::new_door;
+    ///
+    /// let closed = new_door();
+    /// let open = closed.open();
+    /// let again = closed.open(); // error: use of moved value: `closed`
+    /// ```
+    pub fn open(self) -> Open {
+        Open(())
+    }
+
+    pub fn lock(self, key: Key) -> Locked {
+        Locked { key }
+    }
+}
+
+impl Open {
+    pub fn close(self) -> Closed {
+        Closed(())
+    }
+}
+
+impl Locked {
+    /// Unlocks with a matching key. On failure the locked door is handed back, not lost.
+    pub fn unlock(self, key: &Key) -> Result<Closed, Locked> {
+        if *key == self.key {
+            Ok(Closed(()))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+pub struct TypestateExample;
+
+impl Example for TypestateExample {
+    fn name(&self) -> &str {
+        "typestate"
+    }
+
+    fn description(&self) -> &str {
+        "state transitions that consume the old state"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("typestate.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let door = new_door();
+        let door = door.open();
+        writeln!(out, "{:?}", door)?;
+        let door = door.close().lock(Key::cut(1234));
+        writeln!(out, "{:?}", door)?;
+
+        let door = match door.unlock(&Key::cut(9999)) {
+            Ok(closed) => closed,
+            Err(locked) => {
+                writeln!(out, "wrong key, still {:?}", locked)?;
+                locked.unlock(&Key::cut(1234)).expect("the right key")
+            }
+        };
+        writeln!(out, "unlocked: {:?}", door)?;
+        writeln!(out, "{:?}", door.open())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_lock_unlock_open_cycle() {
+        let locked = new_door().open().close().lock(Key::cut(7));
+        let closed = locked.unlock(&Key::cut(7)).expect("matching key");
+        let open = closed.open();
+        let _closed_again = open.close();
+    }
+
+    #[test]
+    fn wrong_key_hands_the_locked_door_back() {
+        let locked = new_door().lock(Key::cut(7));
+        let locked = locked.unlock(&Key::cut(8)).expect_err("wrong key");
+        assert_eq!(locked.key, Key::cut(7));
+        assert!(locked.unlock(&Key::cut(7)).is_ok());
+    }
+}
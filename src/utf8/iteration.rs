@@ -0,0 +1,92 @@
+// Bytes vs Chars -----------------------------------------------
+// len() counts bytes, chars() decodes Unicode scalar values and char_indices() pairs each char with
+// the byte offset it starts at, so the three disagree as soon as the text leaves ASCII
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+use crate::ownership::functions;
+
+/// Number of chars (Unicode scalar values) in `s`, which is not the same as `s.len()`.
+pub fn char_count(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// The `n`th char of `s`, counting from zero, or `None` past the end.
+pub fn nth_char(s: &str, n: usize) -> Option<char> {
+    s.chars().nth(n)
+}
+
+pub struct Utf8IterationExample;
+
+impl Example for Utf8IterationExample {
+    fn name(&self) -> &str {
+        "utf8-iteration"
+    }
+
+    fn description(&self) -> &str {
+        "bytes vs chars vs char_indices"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("iteration.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let s = String::from("café 🦀");
+        let bytes: Vec<u8> = s.bytes().collect();
+        writeln!(out, "bytes:        {} {:?}", bytes.len(), bytes)?;
+        let chars: Vec<char> = s.chars().collect();
+        writeln!(out, "chars:        {} {:?}", chars.len(), chars)?;
+        let indices: Vec<(usize, char)> = s.char_indices().collect();
+        writeln!(out, "char_indices: {:?}", indices)?;
+
+        let (s, len) = functions::calculate_length(s);
+        writeln!(
+            out,
+            "calculate_length({:?}) = {} bytes, {} chars",
+            s,
+            len,
+            char_count(&s)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_bytes_and_chars_agree() {
+        assert_eq!(char_count("hello"), 5);
+        assert_eq!(char_count("hello"), "hello".len());
+        assert_eq!(nth_char("hello", 1), Some('e'));
+    }
+
+    #[test]
+    fn multi_byte_chars_count_once() {
+        let s = "café 🦀";
+        assert_eq!(s.len(), 10);
+        assert_eq!(char_count(s), 6);
+        assert_eq!(nth_char(s, 3), Some('é'));
+        assert_eq!(nth_char(s, 5), Some('🦀'));
+    }
+
+    #[test]
+    fn combining_characters_are_separate_chars() {
+        let s = "cafe\u{301}"; // e followed by a combining acute accent, displayed as é
+        assert_eq!(char_count(s), 5);
+        assert_eq!(nth_char(s, 3), Some('e'));
+        assert_eq!(nth_char(s, 4), Some('\u{301}'));
+    }
+
+    #[test]
+    fn nth_char_out_of_range() {
+        assert_eq!(nth_char("abc", 3), None);
+        assert_eq!(nth_char("", 0), None);
+        assert_eq!(char_count(""), 0);
+    }
+}
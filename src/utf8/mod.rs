@@ -0,0 +1,112 @@
+// UTF-8 and String Slices --------------------------------------
+// A String is stored as UTF-8 bytes and a slice range is counted in bytes, not characters, so a range
+// that ends in the middle of a multi-byte character panics instead of returning half a character
+use std::io::{self, Write};
+use std::ops::Range;
+
+use crate::example::{Example, Rule, RunConfig};
+
+pub mod iteration;
+
+/// Slices `s` by byte range, returning `None` instead of panicking when the range is out of bounds
+/// or doesn't fall on character boundaries.
+pub fn safe_slice(s: &str, range: Range<usize>) -> Option<&str> {
+    if range.start > range.end || !s.is_char_boundary(range.start) || !s.is_char_boundary(range.end)
+    {
+        return None; // is_char_boundary is also false past the end of the string
+    }
+    Some(&s[range])
+}
+
+/// Returns the first `n` characters of `s` (all of it if it is shorter), always on a boundary.
+pub fn first_n_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+pub struct Utf8Example;
+
+impl Example for Utf8Example {
+    fn name(&self) -> &str {
+        "utf8"
+    }
+
+    fn description(&self) -> &str {
+        "slicing on character boundaries"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::Owner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("mod.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let s = String::from("héllo");
+        writeln!(
+            out,
+            "{:?} is {} bytes but {} chars",
+            s,
+            s.len(),
+            s.chars().count()
+        )?;
+        writeln!(out, "&s[0..2] would panic: byte 2 is inside 'é'")?;
+        writeln!(out, "safe_slice(0..2)    = {:?}", safe_slice(&s, 0..2))?;
+        writeln!(out, "safe_slice(0..3)    = {:?}", safe_slice(&s, 0..3))?;
+        writeln!(out, "first_n_chars(s, 2) = {:?}", first_n_chars(&s, 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn indexing_inside_a_char_panics() {
+        let s = String::from("héllo");
+        let result = panic::catch_unwind(|| s[0..2].to_string());
+        assert!(result.is_err());
+        assert_eq!(safe_slice(&s, 0..2), None);
+    }
+
+    #[test]
+    fn safe_slice_on_ascii() {
+        assert_eq!(safe_slice("hello", 1..4), Some("ell"));
+        assert_eq!(safe_slice("hello", 0..5), Some("hello"));
+        assert_eq!(safe_slice("hello", 2..2), Some(""));
+    }
+
+    #[test]
+    fn safe_slice_on_multi_byte_boundaries() {
+        let s = "héllo"; // é takes bytes 1 and 2
+        assert_eq!(safe_slice(s, 0..1), Some("h"));
+        assert_eq!(safe_slice(s, 0..3), Some("hé"));
+        assert_eq!(safe_slice(s, 1..2), None);
+        assert_eq!(safe_slice(s, 2..4), None);
+    }
+
+    #[test]
+    fn safe_slice_past_the_end_and_empty() {
+        assert_eq!(safe_slice("hello", 3..10), None);
+        assert_eq!(safe_slice("hello", 6..6), None);
+        assert_eq!(safe_slice("", 0..0), Some(""));
+        assert_eq!(safe_slice("", 0..1), None);
+        #[allow(clippy::reversed_empty_ranges)] // a backwards range must not panic either
+        let backwards = safe_slice("hello", 3..1);
+        assert_eq!(backwards, None);
+    }
+
+    #[test]
+    fn first_n_chars_counts_chars_not_bytes() {
+        assert_eq!(first_n_chars("hello", 2), "he");
+        assert_eq!(first_n_chars("héllo", 2), "hé");
+        assert_eq!(first_n_chars("héllo", 10), "héllo");
+        assert_eq!(first_n_chars("héllo", 0), "");
+        assert_eq!(first_n_chars("", 3), "");
+    }
+}
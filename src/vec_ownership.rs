@@ -0,0 +1,203 @@
+// Vec and Ownership --------------------------------------------
+// A Vec owns its elements: push moves a value in, indexing only borrows one, and remove, swap_remove
+// and pop hand ownership of an element back out to the caller
+use std::io::{self, Write};
+
+use crate::example::{Example, Rule, RunConfig};
+
+/// Moves the first element out of the Vec, shifting the rest down.
+///
+/// Pushing moves the String into the Vec, so the old binding can't be used any more:
+///
+/// ```compile_fail
+/// let mut v = Vec::new();
+/// let s = String::from("hello");
+/// v.push(s);
+/// println!("{}", s); // error: borrow of moved value: `s`
+/// ```
+///
+/// And while `&v[0]` is held the Vec can't be pushed to, the push might reallocate under the borrow:
+///
+/// ```compile_fail
+/// let mut v = vec![String::from("hello")];
+/// let first = &v[0];
+/// v.push(String::from("world")); // error: cannot borrow `v` as mutable because it is also borrowed as immutable
+/// println!("{}", first);
+/// ```
+#[allow(clippy::ptr_arg)] // remove needs the Vec, not a slice
+pub fn take_first(v: &mut Vec<String>) -> Option<String> {
+    if v.is_empty() {
+        None
+    } else {
+        Some(v.remove(0))
+    }
+}
+
+/// Consumes the Vec and keeps only its longest String (the first one on a tie), without cloning.
+pub fn longest_owned(v: Vec<String>) -> Option<String> {
+    v.into_iter().fold(None, |best, s| match best {
+        Some(b) if b.len() >= s.len() => Some(b),
+        _ => Some(s),
+    })
+}
+
+/// Moves every entry starting with "ERROR" out of the log and returns them, keeping the rest in order.
+///
+/// Borrowing the log with `iter()` only hands out `&String`, and a String can't be moved out from
+/// behind a shared reference:
+///
+/// ```compile_fail
+/// let log = vec![String::from("ERROR disk full")];
+/// let mut errors: Vec<String> = Vec::new();
+/// for entry in log.iter() {
+///     if entry.starts_with("ERROR") {
+///         errors.push(*entry); // error: cannot move out of `*entry` which is behind a shared reference
+///     }
+/// }
+/// ```
+pub fn drain_errors(log: &mut Vec<String>) -> Vec<String> {
+    // extract_if moves only the matching Strings out and shifts the survivors down in place, so
+    // the log keeps its own buffer
+    log.extract_if(.., |entry| entry.starts_with("ERROR"))
+        .collect()
+}
+
+fn layout(out: &mut dyn Write, label: &str, v: &[String], capacity: usize) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<8} ptr {:p}, len {}, capacity {}",
+        label,
+        v.as_ptr(),
+        v.len(),
+        capacity
+    )
+}
+
+pub struct VecOwnershipExample;
+
+impl Example for VecOwnershipExample {
+    fn name(&self) -> &str {
+        "vec-ownership"
+    }
+
+    fn description(&self) -> &str {
+        "push moves in, indexing borrows, remove moves out"
+    }
+
+    fn rule(&self) -> Rule {
+        Rule::OneOwner
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("vec_ownership.rs")
+    }
+
+    fn run(&self, out: &mut dyn Write, _config: &RunConfig) -> io::Result<()> {
+        let mut v: Vec<String> = Vec::new();
+        layout(out, "empty", &v, v.capacity())?;
+        for word in ["one", "two", "three", "four", "five"] {
+            v.push(String::from(word)); // the String moves into the Vec
+            layout(out, "push", &v, v.capacity())?;
+        }
+
+        let first = &v[0]; // a borrow, v still owns the String
+        writeln!(out, "borrowed v[0] = {}", first)?;
+
+        let popped = v.pop(); // ownership comes back out...
+        let swapped = v.swap_remove(0); // ...from the end, or from anywhere with the last one moved in
+        let removed = take_first(&mut v); // ...or from the front, shifting the rest down
+        writeln!(
+            out,
+            "pop {:?}, swap_remove {:?}, remove {:?}",
+            popped, swapped, removed
+        )?;
+        writeln!(out, "left {:?}", v)?;
+        writeln!(out, "longest {:?}", longest_owned(v))?;
+
+        let mut log = vec![
+            String::from("INFO start"),
+            String::from("ERROR disk full"),
+            String::from("INFO retry"),
+            String::from("ERROR disk still full"),
+        ];
+        let errors = drain_errors(&mut log);
+        writeln!(out, "drained {:?}, log keeps {:?}", errors, log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::strings;
+
+    #[test]
+    fn take_first_moves_out_the_front() {
+        let mut v = strings(&["a", "b", "c"]);
+        let ptr = v[0].as_ptr();
+        let first = take_first(&mut v).unwrap();
+        assert_eq!(first.as_ptr(), ptr); // the same heap buffer, moved rather than copied
+        assert_eq!(v, ["b", "c"]);
+    }
+
+    #[test]
+    fn take_first_on_empty_and_single() {
+        let mut v = Vec::new();
+        assert_eq!(take_first(&mut v), None);
+
+        let mut v = strings(&["only"]);
+        assert_eq!(take_first(&mut v).as_deref(), Some("only"));
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn longest_owned_picks_longest() {
+        assert_eq!(longest_owned(Vec::new()), None);
+        assert_eq!(longest_owned(strings(&["one"])).as_deref(), Some("one"));
+        assert_eq!(
+            longest_owned(strings(&["a", "ccc", "bb"])).as_deref(),
+            Some("ccc")
+        );
+    }
+
+    #[test]
+    fn longest_owned_keeps_first_on_tie() {
+        let v = strings(&["ab", "cd", "e"]);
+        let ptr = v[0].as_ptr();
+        let longest = longest_owned(v).unwrap();
+        assert_eq!(longest, "ab");
+        assert_eq!(longest.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn drain_errors_with_no_matches() {
+        let mut log = strings(&["INFO a", "WARN b"]);
+        assert!(drain_errors(&mut log).is_empty());
+        assert_eq!(log, ["INFO a", "WARN b"]);
+    }
+
+    #[test]
+    fn drain_errors_with_all_matches() {
+        let mut log = strings(&["ERROR a", "ERROR b"]);
+        assert_eq!(drain_errors(&mut log), ["ERROR a", "ERROR b"]);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn drain_errors_keeps_relative_order() {
+        let mut log = strings(&["INFO 1", "ERROR 2", "INFO 3", "ERROR 4", "INFO 5"]);
+        let ptr = log[1].as_ptr();
+        let errors = drain_errors(&mut log);
+        assert_eq!(errors, ["ERROR 2", "ERROR 4"]);
+        assert_eq!(errors[0].as_ptr(), ptr); // moved out, not cloned
+        assert_eq!(log, ["INFO 1", "INFO 3", "INFO 5"]);
+    }
+
+    #[test]
+    fn drain_errors_keeps_the_logs_buffer() {
+        let mut log = strings(&["ERROR 1", "INFO 2", "ERROR 3", "INFO 4"]);
+        let (ptr, capacity) = (log.as_ptr(), log.capacity());
+        drain_errors(&mut log);
+        assert_eq!(log.as_ptr(), ptr);
+        assert_eq!(log.capacity(), capacity);
+    }
+}
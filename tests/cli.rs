@@ -0,0 +1,117 @@
+use std::process::{Command, Output};
+
+use ownership::example::registry;
+
+fn ownership(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ownership"))
+        .args(args)
+        .current_dir(env!("CARGO_TARGET_TMPDIR")) // keeps the progress file out of the repo
+        .output()
+        .expect("failed to run the ownership binary")
+}
+
+#[test]
+fn named_example_runs_alone() {
+    let output = ownership(&["references"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("The length of 'hello' is 5\n"));
+    assert!(!stdout.contains("==="));
+}
+
+#[test]
+fn unknown_example_fails_and_lists_names() {
+    let output = ownership(&["nope"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown example `nope`"));
+    assert!(stderr.contains("moves"));
+    assert!(stderr.contains("references"));
+}
+
+#[test]
+fn no_arguments_runs_the_whole_walkthrough() {
+    let output = ownership(&[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let count = registry().len();
+    let first = format!(
+        "=== [1/{}] Strings: heap allocated, growable text ===\nhello\n",
+        count
+    );
+    assert!(stdout.starts_with(&first));
+    assert!(stdout.contains("[2, 3]\n"));
+    let footer = format!("=== ran {} examples in ", count);
+    assert!(stdout.lines().last().unwrap().starts_with(&footer));
+}
+
+#[test]
+fn quiz_reads_answers_from_stdin() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ownership"))
+        .arg("quiz")
+        .current_dir(env!("CARGO_TARGET_TMPDIR"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run the ownership binary");
+    child.stdin.take().unwrap().write_all(b"2\n1\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("Question 1/"));
+    assert!(stdout.contains("quiz ended early"));
+    assert!(stdout.lines().last().unwrap().starts_with("score: 1/"));
+}
+
+#[test]
+fn quiz_reports_an_invalid_question_file() {
+    let path = std::env::temp_dir().join(format!("ownership-quiz-{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"[{"prompt": "p", "choices": ["a", "b"], "correct": 5, "explanation": "e"}]"#,
+    )
+    .unwrap();
+    let output = ownership(&["quiz", "--file", path.to_str().unwrap()]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("question 0: correct index 5 is out of range"));
+}
+
+#[test]
+fn quiz_rejects_a_seed_without_shuffle() {
+    let output = ownership(&["quiz", "--seed", "7"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("`--seed` only applies together with `--shuffle`"));
+}
+
+#[test]
+fn color_before_json_format_is_accepted_and_ignored() {
+    let output = ownership(&["--color=always", "--format", "json", "moves"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(r#""name": "moves""#));
+    assert!(!stdout.contains("\\u001b")); // no escape codes, not even quoted ones
+}
+
+#[test]
+fn leftover_arguments_are_rejected() {
+    for args in [
+        &["moves", "extra-junk"][..],
+        &["--list", "extra-junk"],
+        &["quiz", "extra-junk"],
+    ] {
+        let output = ownership(args);
+        assert!(!output.status.success(), "{:?}", args);
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            stderr.contains("unexpected argument `extra-junk`"),
+            "{:?}",
+            args
+        );
+    }
+}
@@ -0,0 +1,7 @@
+// Each file under tests/compile_fail/ must fail to compile with exactly the diagnostic in its
+// .stderr file. Regenerate the expectations with `TRYBUILD=overwrite cargo test --test compile_fail`
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
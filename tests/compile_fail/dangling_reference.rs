@@ -0,0 +1,10 @@
+// The String is dropped when dangle returns, so a reference to it would dangle
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
+
+fn main() {
+    let reference_to_nothing = dangle();
+    println!("{}", reference_to_nothing);
+}
@@ -0,0 +1,13 @@
+// Copy is a bitwise copy; a String field would end up with two owners of one heap buffer
+#[derive(Clone, Copy)]
+struct Label {
+    text: String,
+}
+
+fn main() {
+    let label = Label {
+        text: String::from("hello"),
+    };
+    let copy = label;
+    println!("{} {}", label.text, copy.text);
+}
@@ -0,0 +1,23 @@
+// A `&mut self` method needs the whole struct, so it can't be called while a field is borrowed
+struct Playlist {
+    songs: Vec<String>,
+}
+
+impl Playlist {
+    fn first(&self) -> &String {
+        &self.songs[0]
+    }
+
+    fn add(&mut self, song: String) {
+        self.songs.push(song);
+    }
+}
+
+fn main() {
+    let mut playlist = Playlist {
+        songs: vec![String::from("one")],
+    };
+    let first = playlist.first();
+    playlist.add(String::from("two"));
+    println!("{}", first);
+}
@@ -0,0 +1,7 @@
+// push may reallocate the Vec, which would leave `first` pointing at freed memory
+fn main() {
+    let mut names = vec![String::from("ana"), String::from("bo")];
+    let first = &names[0];
+    names.push(String::from("cy"));
+    println!("{}", first);
+}
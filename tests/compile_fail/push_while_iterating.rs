@@ -0,0 +1,10 @@
+// `for s in &v` borrows v for the whole loop, so the loop body can't push to it
+fn main() {
+    let mut v = vec![String::from("a"), String::from("b")];
+    for s in &v {
+        if s == "a" {
+            v.push(String::from("c"));
+        }
+    }
+    println!("{:?}", v);
+}
@@ -0,0 +1,10 @@
+// Removing would shift the elements the loop is still walking over, so it isn't allowed either
+fn main() {
+    let mut v = vec![String::from("a"), String::from("b")];
+    for (i, s) in v.iter().enumerate() {
+        if s.is_empty() {
+            v.remove(i);
+        }
+    }
+    println!("{:?}", v);
+}
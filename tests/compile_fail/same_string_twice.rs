@@ -0,0 +1,11 @@
+// The one-mutable-reference rule holds across function calls: both arguments can't be `&mut s`
+fn append(dst: &mut String, src: &mut String) {
+    dst.push_str(src);
+    src.clear();
+}
+
+fn main() {
+    let mut s = String::from("hello");
+    append(&mut s, &mut s);
+    println!("{}", s);
+}
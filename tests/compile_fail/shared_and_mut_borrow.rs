@@ -0,0 +1,8 @@
+// A mutable borrow can't start while immutable borrows are still in use
+fn main() {
+    let mut string_1 = String::from("hello");
+    let refer_1 = &string_1; // no problem
+    let refer_2 = &string_1; // no problem
+    let refer_3 = &mut string_1; // BIG PROBLEM
+    println!("{}, {}, and {}", refer_1, refer_2, refer_3);
+}
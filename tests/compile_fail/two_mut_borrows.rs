@@ -0,0 +1,7 @@
+// Only one mutable reference to a piece of data in a particular scope
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &mut s;
+    let r2 = &mut s;
+    println!("{}, {}", r1, r2);
+}
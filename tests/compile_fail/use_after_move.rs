@@ -0,0 +1,7 @@
+// Rule 2: after `let s2 = s1;` the String has one owner, s2, and s1 can't be used any more
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{}, world!", s1);
+    println!("{}", s2);
+}
@@ -0,0 +1,23 @@
+use std::process::Command;
+
+/// The test binary running this was built with one side of the `solutions` cfg, so check that
+/// the exercises, and the tests against them, also compile with the other side.
+#[test]
+fn the_other_exercises_configuration_compiles() {
+    let mut cargo = Command::new(env!("CARGO"));
+    cargo
+        .args(["check", "--quiet", "--lib", "--tests"])
+        .arg("--manifest-path")
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/exercises-check"));
+    if !cfg!(feature = "solutions") {
+        cargo.args(["--features", "solutions"]);
+    }
+    let output = cargo.output().expect("failed to run cargo");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,169 @@
+// Runs every registered example and compares its output with tests/golden/<name>.txt.
+// `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` rewrites the files instead. Output that changes
+// with a feature is kept in <name>.<feature>.txt, so update once more with `--all-features`
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ownership::example::{registry, RunConfig};
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+/// The feature whose output gets its own golden files, if it's enabled.
+fn variant() -> Option<&'static str> {
+    cfg!(feature = "count-allocs").then_some("count-allocs")
+}
+
+/// Writes `actual` as the golden output of `name`, as a variant only if it differs from the base.
+fn update(dir: &Path, name: &str, actual: &str) {
+    fs::create_dir_all(dir).unwrap();
+    let base = dir.join(format!("{}.txt", name));
+    match variant() {
+        Some(feature) => {
+            let variant = dir.join(format!("{}.{}.txt", name, feature));
+            match fs::read_to_string(&base) {
+                Ok(expected) if expected != actual => fs::write(variant, actual).unwrap(),
+                Ok(_) => {
+                    let _ = fs::remove_file(variant); // the base covers it, if it existed it's stale
+                }
+                Err(_) => fs::write(base, actual).unwrap(),
+            }
+        }
+        None => fs::write(base, actual).unwrap(),
+    }
+}
+
+/// The golden file `name` is compared with: its variant if there is one, else the base.
+fn golden_path(dir: &Path, name: &str) -> PathBuf {
+    variant()
+        .map(|feature| dir.join(format!("{}.{}.txt", name, feature)))
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| dir.join(format!("{}.txt", name)))
+}
+
+/// Replaces what changes from run to run: `0x`-prefixed addresses and durations.
+fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let boundary = !normalized.ends_with(|p: char| p.is_alphanumeric() || p == '.');
+        if boundary && rest.starts_with("0x") {
+            let hex = rest[2..]
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .unwrap_or(rest.len() - 2);
+            if hex > 0 {
+                normalized.push_str("<ptr>");
+                rest = &rest[2 + hex..];
+                continue;
+            }
+        }
+        if boundary && c.is_ascii_digit() {
+            if let Some(len) = duration_len(rest) {
+                normalized.push_str("<ms>");
+                rest = &rest[len..];
+                continue;
+            }
+        }
+        normalized.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    normalized
+}
+
+/// Length of a `Debug`-formatted Duration such as `1.74ms` at the start of `s`, if there is one.
+fn duration_len(s: &str) -> Option<usize> {
+    let number = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let unit = ["ns", "µs", "ms", "s"]
+        .iter()
+        .find(|unit| s[number..].starts_with(*unit))?;
+    let end = number + unit.len();
+    match s[end..].chars().next() {
+        Some(c) if c.is_alphanumeric() => None,
+        _ => Some(end),
+    }
+}
+
+/// A line diff of `expected` against `actual`, `-` for missing lines and `+` for new ones.
+fn diff(expected: &str, actual: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    // lcs[i][j] is the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j, mut out) = (0, 0, String::new());
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push_str(&format!("  {}\n", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push_str(&format!("- {}\n", old[i]));
+            i += 1;
+        } else {
+            out.push_str(&format!("+ {}\n", new[j]));
+            j += 1;
+        }
+    }
+    out
+}
+
+#[test]
+fn every_example_matches_its_golden_output() {
+    let updating = env::var_os("UPDATE_SNAPSHOTS").is_some_and(|v| v == "1");
+    let dir = golden_dir();
+    let mut failures = Vec::new();
+    for example in registry() {
+        let mut out = Vec::new();
+        example.run(&mut out, &RunConfig::default()).unwrap();
+        let actual = normalize(&String::from_utf8(out).unwrap());
+        if updating {
+            update(&dir, example.name(), &actual);
+            continue;
+        }
+        let path = golden_path(&dir, example.name());
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "{} differs from {}:\n{}",
+                example.name(),
+                path.display(),
+                diff(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!("{} has no {}", example.name(), path.display())),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{}\nrerun with UPDATE_SNAPSHOTS=1 to accept the new output",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn normalize_hides_pointers_and_durations() {
+    assert_eq!(
+        normalize("ptr 0x55b3b197cf10, len 5 in 1.74ms or 15µs, 3s"),
+        "ptr <ptr>, len 5 in <ms> or <ms>, <ms>"
+    );
+    assert_eq!(
+        normalize("0x is 10 units, 8 bytes"),
+        "0x is 10 units, 8 bytes"
+    );
+    assert_eq!(normalize("s1 x2s 0xfg"), "s1 x2s <ptr>g");
+}
+
+#[test]
+fn diff_marks_changed_lines() {
+    assert_eq!(diff("a\nb\nc\n", "a\nx\nc\n"), "  a\n- b\n+ x\n  c\n");
+    assert_eq!(diff("a\n", "a\nb\n"), "  a\n+ b\n");
+}